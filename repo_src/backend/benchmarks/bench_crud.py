@@ -0,0 +1,65 @@
+"""
+Timing baseline for the item storage path: create, list (first page) at several
+table sizes, and delete. Runs the real endpoint functions against a private
+in-memory SQLite database, so nothing touches the development database.
+
+Run from the project root:
+    python -m repo_src.backend.benchmarks.bench_crud
+
+The default row counts finish in a few seconds for CI; set BENCH_FULL=true to
+add larger tables.
+"""
+import os
+import statistics
+import time
+from typing import Callable, List
+
+# Select the in-memory test database before the app's engine is created
+os.environ.setdefault("TESTING", "1")
+os.environ.setdefault("TEST_DATABASE_URL", "sqlite:///:memory:")
+
+from repo_src.backend.config import env_bool
+from repo_src.backend.database.connection import Base, SessionLocal, engine
+from repo_src.backend.database import models  # noqa Registers the tables on Base
+from repo_src.backend.data.schemas import ItemCreate
+from repo_src.backend.functions.items import LIST_ORDER, delete_item, insert_item, paginate, scoped_items
+
+ROW_COUNTS = [100, 1_000, 10_000, 50_000] if env_bool("BENCH_FULL") else [100, 1_000]
+LIST_REPEATS = 20
+DELETE_COUNT = 100
+
+def timed(operation: Callable[[], object], repeats: int) -> List[float]:
+    """Run `operation` `repeats` times and return each duration in seconds"""
+    durations = []
+    for _ in range(repeats):
+        start = time.perf_counter()
+        operation()
+        durations.append(time.perf_counter() - start)
+    return durations
+
+def report(label: str, durations: List[float]) -> None:
+    median_us = statistics.median(durations) * 1_000_000
+    print(f"{label:<32} median {median_us:10.1f} us  ({len(durations)} runs)")
+
+def bench_table_size(rows: int) -> None:
+    Base.metadata.drop_all(bind=engine)
+    Base.metadata.create_all(bind=engine)
+    db = SessionLocal()
+    try:
+        counter = iter(range(rows))
+        report(f"create ({rows} rows)", timed(lambda: insert_item(db, ItemCreate(name=f"item {next(counter)}"), None), rows))
+        report(
+            f"list first page ({rows} rows)",
+            timed(lambda: paginate(scoped_items(db, None).order_by(*LIST_ORDER), 1, 100), LIST_REPEATS),
+        )
+        ids = iter(range(1, DELETE_COUNT + 1))
+        report(f"delete ({rows} rows)", timed(lambda: delete_item(next(ids), db, None), min(DELETE_COUNT, rows)))
+    finally:
+        db.close()
+
+def main() -> None:
+    for rows in ROW_COUNTS:
+        bench_table_size(rows)
+
+if __name__ == "__main__":
+    main()
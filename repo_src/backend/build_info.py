@@ -0,0 +1,30 @@
+import os
+import subprocess
+from typing import Optional
+
+from repo_src.backend.config import env_str
+
+# Identifies the running build for /api/version. The deploy pipeline can set
+# GIT_COMMIT and BUILD_TIME (e.g. as Docker build args); without GIT_COMMIT the
+# commit is read from the git checkout the app runs from, if there is one.
+# Both are read once at import so the endpoint stays cheap.
+
+def git_commit() -> Optional[str]:
+    commit = env_str("GIT_COMMIT")
+    if commit:
+        return commit
+    try:
+        result = subprocess.run(
+            ["git", "rev-parse", "HEAD"],
+            cwd=os.path.dirname(os.path.abspath(__file__)),
+            capture_output=True,
+            text=True,
+            timeout=2,
+            check=True,
+        )
+    except (OSError, subprocess.SubprocessError):
+        return None
+    return result.stdout.strip() or None
+
+GIT_COMMIT = git_commit()
+BUILD_TIME = env_str("BUILD_TIME")
@@ -0,0 +1,39 @@
+import os
+from typing import Optional
+
+# Helpers for reading typed settings from environment variables.
+# Unset or blank variables fall back to the given default.
+
+def env_str(name: str, default: Optional[str] = None) -> Optional[str]:
+    value = os.getenv(name)
+    if value is None or value.strip() == "":
+        return default
+    return value.strip()
+
+def env_bool(name: str, default: bool = False) -> bool:
+    value = env_str(name)
+    if value is None:
+        return default
+    return value.lower() in ("1", "true", "yes", "on")
+
+def env_int(name: str, default: int) -> int:
+    value = env_str(name)
+    if value is None:
+        return default
+    try:
+        return int(value)
+    except ValueError:
+        print(f"Ignoring invalid integer for {name}: {value!r}, using {default}")
+        return default
+
+def dangerous_operations_enabled() -> bool:
+    """Whether destructive bulk operations are allowed, from `DANGEROUS_OPERATIONS`"""
+    return env_bool("DANGEROUS_OPERATIONS")
+
+def base_path() -> str:
+    """
+    The URL sub-path the app is served under, from `SITE_BASE_PATH`, normalised
+    to a leading slash and no trailing slash (e.g. "/app"). Empty when unset.
+    """
+    value = (env_str("SITE_BASE_PATH") or "").strip("/")
+    return f"/{value}" if value else ""
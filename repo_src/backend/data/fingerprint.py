@@ -0,0 +1,13 @@
+import hashlib
+import json
+from typing import Optional
+
+# Stable fingerprint of an item's content, for ETags and duplicate detection.
+# Only the fields a user edits are included, not id, version, owner or timestamps,
+# so two items with the same text hash the same. Changing the algorithm changes
+# every ETag clients hold, so tests pin its output.
+
+def content_hash(name: str, description: Optional[str], kind: str) -> str:
+    """A 16-character hex SHA-256 digest of the item's name, description and kind"""
+    canonical = json.dumps([name, description, kind], ensure_ascii=False, separators=(",", ":"))
+    return hashlib.sha256(canonical.encode("utf-8")).hexdigest()[:16]
@@ -0,0 +1,33 @@
+from repo_src.backend.config import env_bool
+
+# Optional Markdown for item descriptions, enabled with MARKDOWN_ENABLED. Items
+# created with `markdown: true` then get a `description_html` field rendered here;
+# all other text is returned as plain text for the frontend to escape. The
+# `markdown` and `nh3` packages are only imported when rendering is enabled.
+
+# Everything else, including <script>, <img>, <iframe>, event handler and style
+# attributes, is removed from the rendered HTML
+ALLOWED_TAGS = {
+    "p", "br", "hr", "em", "strong", "del", "code", "pre", "blockquote",
+    "ul", "ol", "li", "a", "h1", "h2", "h3", "h4", "h5", "h6",
+}
+ALLOWED_ATTRIBUTES = {"a": {"href", "title"}}
+# Links to javascript:, data: and other schemes lose their href
+ALLOWED_URL_SCHEMES = {"http", "https", "mailto"}
+
+def markdown_enabled() -> bool:
+    return env_bool("MARKDOWN_ENABLED")
+
+def render_item_markdown(text: str) -> str:
+    """Render Markdown to HTML and sanitize it down to ALLOWED_TAGS, so it is safe to insert into the page"""
+    import markdown
+    import nh3
+
+    html = markdown.markdown(text)
+    return nh3.clean(
+        html,
+        tags=ALLOWED_TAGS,
+        attributes=ALLOWED_ATTRIBUTES,
+        url_schemes=ALLOWED_URL_SCHEMES,
+        link_rel="noopener noreferrer nofollow",
+    )
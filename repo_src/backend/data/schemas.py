@@ -1,25 +1,213 @@
-from pydantic import BaseModel
-from typing import Optional
+from pydantic import AfterValidator, BaseModel, ConfigDict, Field, computed_field, field_validator
+from typing import Annotated, Any, Dict, Generic, List, Literal, Optional, TypeVar
 from datetime import datetime
+from enum import Enum
+
+from repo_src.backend.data.fingerprint import content_hash
+from repo_src.backend.data.markdown_render import markdown_enabled, render_item_markdown
+from repo_src.backend.data.validators import clean_item_name
+
+class ItemKind(str, Enum):
+    """The known kinds of item, stored as text in items.kind"""
+    ITEM = "item"
+    NOTE = "note"
+    TASK = "task"
+
+# Item name as accepted from clients. Every request schema that takes a name uses
+# this type, so the rules in data/validators.py apply everywhere without each
+# schema declaring its own validator. Responses use plain str, so names stored
+# before a rule changed can still be read.
+ItemName = Annotated[str, AfterValidator(clean_item_name)]
 
 class ItemBase(BaseModel):
+    # Keep kind as its plain string value so it can be written straight to the database
+    model_config = ConfigDict(use_enum_values=True, validate_default=True)
+
     name: str
     description: Optional[str] = None
+    kind: ItemKind = ItemKind.ITEM
+    # The description is Markdown; see data/markdown_render.py
+    markdown: bool = False
+    completed: bool = False
+
+    def content_hash(self) -> str:
+        """Fingerprint of name, description and kind; see data/fingerprint.py"""
+        return content_hash(self.name, self.description, self.kind)
+
+class ItemFilter(BaseModel):
+    """
+    Conditions for listing items; each field that is set narrows the result.
+    `text` matches anywhere in the name or description, and `from`/`to` bound
+    the creation time (inclusive).
+    """
+    model_config = ConfigDict(frozen=True, populate_by_name=True)
+
+    text: Optional[str] = None
+    from_: Optional[datetime] = Field(None, alias="from")
+    to: Optional[datetime] = None
+    kind: Optional[ItemKind] = None
+    completed: Optional[bool] = None
+
+    def matches_everything(self) -> bool:
+        """True when no condition is set, so every item would match"""
+        return (
+            not self.text and self.from_ is None and self.to is None
+            and self.kind is None and self.completed is None
+        )
+
+class DeleteFilteredRequest(ItemFilter):
+    """An ItemFilter selecting items to trash; `confirm` is required when it sets no condition"""
+    confirm: bool = False
 
 class ItemCreate(ItemBase):
     """Schema for creating a new item"""
-    pass
+    name: ItemName
 
 class ItemUpdate(BaseModel):
     """Schema for updating an existing item"""
-    name: Optional[str] = None
+    name: Optional[ItemName] = None
     description: Optional[str] = None
+    version: int  # Version the client last read; the update is rejected if it has changed
+
+class ItemPatch(BaseModel):
+    """Schema for a partial update; only the fields present in the request are changed"""
+    name: Optional[ItemName] = None
+    description: Optional[str] = None
+    completed: Optional[bool] = None
+    version: Optional[int] = None  # When sent, the patch is rejected if the item has changed since
+
+    @field_validator("name", "completed")
+    @classmethod
+    def not_null(cls, value, info):
+        if value is None:
+            raise ValueError(f"{info.field_name} cannot be null")
+        return value
 
 class ItemResponse(ItemBase):
     """Schema for returning item data in responses"""
     id: int
+    user_id: Optional[str] = None
+    version: int
+    position: Optional[int] = None
     created_at: datetime
     updated_at: datetime
+    bumped_at: Optional[datetime] = None
+    deleted_at: Optional[datetime] = None
+
+    @computed_field
+    @property
+    def description_html(self) -> Optional[str]:
+        """Sanitized HTML of a Markdown description; None unless MARKDOWN_ENABLED is set"""
+        if not self.markdown or not self.description or not markdown_enabled():
+            return None
+        return render_item_markdown(self.description)
     
     class Config:
-        from_attributes = True # Updated from orm_mode for Pydantic V2 compatibility 
\ No newline at end of file
+        from_attributes = True # Updated from orm_mode for Pydantic V2 compatibility 
+
+class ItemDiff(BaseModel):
+    """Items added, updated and trashed between two points in time, in their current state"""
+    added: List[ItemResponse]
+    updated: List[ItemResponse]
+    deleted: List[ItemResponse]
+
+# Version of the JSON export/backup format. Bump it when ItemResponse changes in
+# a way older readers can't handle, and teach backup.parse_export to migrate the old one.
+EXPORT_SCHEMA_VERSION = 1
+
+class ItemExport(BaseModel):
+    """Top-level envelope of JSON exports and backups"""
+    schema_version: int = EXPORT_SCHEMA_VERSION
+    items: List[ItemResponse]
+
+class LoginRequest(BaseModel):
+    username: str
+    password: str
+
+class DeleteMatchingRequest(BaseModel):
+    """A SQL LIKE pattern for item names; `force` allows patterns that match everything"""
+    pattern: str
+    force: bool = False
+
+class DeleteMatchingResponse(BaseModel):
+    deleted: int
+
+class DeleteIdsRequest(BaseModel):
+    """Ids of the items to move to the trash, such as a selection in the UI"""
+    ids: List[int]
+
+class DeleteIdsResponse(BaseModel):
+    """Ids that were trashed, and requested ids that were already gone (deleted or never existed)"""
+    deleted: List[int]
+    missing: List[int]
+
+class ReorderRequest(BaseModel):
+    """The complete new order of items, as a list of ids"""
+    order: List[int]
+
+class FieldError(BaseModel):
+    """A validation message tied to the request field it applies to"""
+    field: str
+    message: str
+
+class ValidationErrorResponse(BaseModel):
+    """Body of a 400/422 validation response: a combined message plus per-field errors"""
+    detail: str
+    errors: List[FieldError]
+
+class BatchItemResult(BaseModel):
+    """Outcome of a single element in a batch create request"""
+    index: int
+    id: Optional[int] = None
+    error: Optional[str] = None
+
+class BatchCreateResponse(BaseModel):
+    """Per-element results and totals for a batch create request"""
+    results: List[BatchItemResult]
+    inserted: int
+    failed: int
+
+class ItemEventResponse(BaseModel):
+    """One entry of the item change log; `payload` is the item after the change"""
+    seq: int
+    kind: str
+    item_id: int
+    payload: Dict[str, Any]
+    created_at: datetime
+
+class VersionInfo(BaseModel):
+    """What is deployed: app version, git commit, build time and enabled optional features"""
+    version: str
+    commit: Optional[str] = None
+    build_time: Optional[str] = None
+    features: List[str]
+
+class Settings(BaseModel):
+    """
+    UI preferences the server stores, with their defaults. Only these keys can be
+    stored, and each value is validated against its field's type when written.
+    """
+    model_config = ConfigDict(extra="forbid")
+
+    page_size: int = Field(20, ge=1, le=100)
+    theme: Literal["system", "light", "dark"] = "system"
+    hide_completed: bool = False
+
+class SettingValue(BaseModel):
+    """Body of a setting write, and the response for a single setting"""
+    value: Any
+
+class DayCount(BaseModel):
+    """Number of items created on one day (YYYY-MM-DD, UTC)"""
+    date: str
+    count: int
+
+T = TypeVar("T")
+
+class Page(BaseModel, Generic[T]):
+    """One page of a paginated listing, shared by all paginated endpoints"""
+    items: List[T]
+    total: int
+    page: int
+    per_page: int
+    has_next: bool
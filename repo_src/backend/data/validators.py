@@ -0,0 +1,53 @@
+import re
+import unicodedata
+
+from repo_src.backend.config import env_bool
+
+# Rules applied to item input by the request schemas in data/schemas.py.
+# Kept free of FastAPI/SQLAlchemy imports so they can be reused and unit tested directly.
+# The frontend checks names with a copy of these rules (src/utils/itemName.ts);
+# both are tested against the cases in data/item_name_cases.json.
+
+def normalize_item_text(text: str) -> str:
+    """Trim leading/trailing whitespace and collapse internal runs of whitespace to single spaces"""
+    return " ".join(text.split())
+
+# Longest allowed item name, also enforced by a CHECK constraint on items.name.
+# Length is counted in Unicode code points, as len() and the database's length()
+# do, not bytes: 100 emoji or accented letters fit. Names are NFC-normalized first
+# so an accented letter typed as letter + combining accent counts once.
+# (Grapheme clusters aren't used because the CHECK constraint can't count them.)
+ITEM_NAME_MAX_LENGTH = 100
+NAME_REQUIRED_MESSAGE = "Name is required"
+NAME_TOO_LONG_MESSAGE = f"Name must be at most {ITEM_NAME_MAX_LENGTH} characters"
+NAME_MULTILINE_MESSAGE = "Name must be a single line"
+
+# Control characters other than tab/newline, and the bidirectional override and
+# isolate characters that can make displayed text differ from what is stored
+UNSAFE_CHARACTERS = re.compile("[\x00-\x08\x0b\x0c\x0e-\x1f\x7f\u202a-\u202e\u2066-\u2069]")
+
+def sanitize_item_text(text: str) -> str:
+    """
+    Remove invisible characters that have no place in item text. Markup such as
+    `<script>` is kept as typed: item text is plain text, and every display path
+    (React, the RSS feed) escapes it when rendering, so it stays inert.
+    """
+    return UNSAFE_CHARACTERS.sub("", text)
+
+def clean_item_name(name: str) -> str:
+    """
+    Sanitize and NFC-normalize an item name, and normalise its whitespace when
+    NORMALIZE_ITEM_TEXT is enabled. Raises ValueError if the result is blank, longer
+    than ITEM_NAME_MAX_LENGTH, or contains a line break while ITEM_NAME_MULTILINE is off.
+    """
+    name = unicodedata.normalize("NFC", sanitize_item_text(name))
+    if env_bool("NORMALIZE_ITEM_TEXT"):
+        name = normalize_item_text(name)
+    if not name.strip():
+        raise ValueError(NAME_REQUIRED_MESSAGE)
+    # Pasted multi-line names are kept by default
+    if not env_bool("ITEM_NAME_MULTILINE", default=True) and ("\n" in name or "\r" in name):
+        raise ValueError(NAME_MULTILINE_MESSAGE)
+    if len(name) > ITEM_NAME_MAX_LENGTH:
+        raise ValueError(NAME_TOO_LONG_MESSAGE)
+    return name
@@ -0,0 +1,87 @@
+import json
+from pathlib import Path
+from pydantic import ValidationError
+from typing import Any, List
+
+from repo_src.backend.database.connection import SessionLocal
+from repo_src.backend.database.models import Item
+from repo_src.backend.data.schemas import EXPORT_SCHEMA_VERSION, ItemExport, ItemResponse
+from repo_src.backend.data.validators import clean_item_name
+
+class BackupError(Exception):
+    """Raised when a backup cannot be written or a restore cannot be applied"""
+
+def backup_to_file(path: Path) -> int:
+    """
+    Writes every row of the items table, including trashed items, to a JSON file
+    in the versioned export format. Returns the number of items written.
+    """
+    db = SessionLocal()
+    try:
+        items = [ItemResponse.model_validate(item) for item in db.query(Item).order_by(Item.id)]
+    finally:
+        db.close()
+    try:
+        path.write_text(ItemExport(items=items).model_dump_json(indent=2))
+    except OSError as e:
+        raise BackupError(f"Could not write backup to {path}: {e.strerror}") from e
+    return len(items)
+
+def parse_export(data: Any, source: str) -> List[ItemResponse]:
+    """
+    Reads the items out of a JSON export or backup. Files from before the envelope
+    was introduced are a bare array of items and are accepted as-is; files from a
+    newer schema version are rejected, as are items whose name the API would reject.
+    """
+    if isinstance(data, list):
+        # Pre-envelope files hold the same item shape as version 1
+        data = {"schema_version": 1, "items": data}
+    if not isinstance(data, dict) or not isinstance(data.get("schema_version"), int):
+        raise BackupError(f"{source} is not an items export: expected an object with schema_version and items")
+    version = data["schema_version"]
+    if version > EXPORT_SCHEMA_VERSION:
+        raise BackupError(
+            f"{source} uses export schema version {version}, but this version of the app "
+            f"only reads up to version {EXPORT_SCHEMA_VERSION}; upgrade the app to import it"
+        )
+    try:
+        items = ItemExport.model_validate(data).items
+    except ValidationError as e:
+        raise BackupError(f"{source} contains an invalid item: {e}") from e
+    for item in items:
+        try:
+            item.name = clean_item_name(item.name)
+        except ValueError as e:
+            raise BackupError(f"{source} contains an invalid item (id {item.id}): {e}") from e
+    return items
+
+def restore_from_file(path: Path, force: bool = False) -> int:
+    """
+    Reinserts items from a backup file in a single transaction, keeping their ids
+    and timestamps. Refuses to run against a non-empty table unless `force` is
+    set, in which case existing items are deleted first. Returns the number of
+    items restored.
+    """
+    try:
+        data = json.loads(path.read_text())
+    except OSError as e:
+        raise BackupError(f"Could not read backup from {path}: {e.strerror}") from e
+    except json.JSONDecodeError as e:
+        raise BackupError(f"Backup file {path} is not valid JSON: {e}") from e
+    items = parse_export(data, f"Backup file {path}")
+
+    db = SessionLocal()
+    try:
+        existing = db.query(Item).count()
+        if existing and not force:
+            raise BackupError(f"The items table already has {existing} rows; pass --force to replace them")
+        if existing:
+            db.query(Item).delete()
+        db.add_all([Item(**item.model_dump(exclude={"description_html"})) for item in items])
+        db.commit()
+    except Exception:
+        db.rollback()
+        raise
+    finally:
+        db.close()
+    return len(items)
@@ -0,0 +1,75 @@
+import asyncio
+import time
+from enum import Enum
+from typing import Optional
+
+from sqlalchemy import text
+
+from repo_src.backend.database.connection import engine
+
+class AppStatus(str, Enum):
+    """Lifecycle state reported by /ready"""
+    STARTING = "starting"  # Startup (database setup and migrations) hasn't finished
+    READY = "ready"
+    DEGRADED = "degraded"  # Started, but the latest database check failed
+
+class PoolHealth:
+    """
+    The outcome of the most recent `SELECT 1` against the database, a running
+    count of failed checks since startup, and the app status derived from them.
+    """
+
+    def __init__(self):
+        self.last_checked_at: Optional[float] = None
+        self.last_error: Optional[str] = None
+        self.failures = 0
+        self.status = AppStatus.STARTING
+
+    def set_status(self, status: AppStatus) -> None:
+        if status != self.status:
+            print(f"App status: {self.status.value} -> {status.value}")
+            self.status = status
+
+    def mark_started(self) -> None:
+        """Leave STARTING once startup is complete; later checks move between READY and DEGRADED"""
+        self.set_status(AppStatus.READY if self.healthy else AppStatus.DEGRADED)
+
+    @property
+    def healthy(self) -> bool:
+        return self.last_error is None
+
+    def check(self) -> bool:
+        """Run `SELECT 1` on a pooled connection and record the result"""
+        try:
+            with engine.connect() as conn:
+                conn.execute(text("SELECT 1"))
+        except Exception as e:
+            self.failures += 1
+            self.last_error = repr(e)
+            print(f"Database keepalive failed ({self.failures} failures so far): {e!r}")
+        else:
+            self.last_error = None
+        self.last_checked_at = time.time()
+        if self.status != AppStatus.STARTING:
+            self.set_status(AppStatus.READY if self.healthy else AppStatus.DEGRADED)
+        return self.healthy
+
+pool_health = PoolHealth()
+
+async def run_startup(init) -> None:
+    """
+    Run the blocking startup work `init` (database setup and migrations) off the
+    event loop, so the server answers /ready with `starting` meanwhile, then
+    check the pool and leave STARTING. Errors from `init` propagate and leave
+    the status at STARTING; the caller decides how to stop.
+    """
+    await asyncio.to_thread(init)
+    await asyncio.to_thread(pool_health.check)
+    pool_health.mark_started()
+
+async def keepalive_loop(interval_seconds: int):
+    """Checks the pool every `interval_seconds` until cancelled"""
+    while True:
+        await asyncio.sleep(interval_seconds)
+        # The check blocks on the driver, so keep it off the event loop
+        await asyncio.to_thread(pool_health.check)
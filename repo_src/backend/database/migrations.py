@@ -0,0 +1,207 @@
+from sqlalchemy import Column, Index, MetaData, Table, create_engine, inspect, text
+from sqlalchemy.engine import Engine
+from sqlalchemy.pool import StaticPool
+from typing import Dict, List, Tuple, Union
+
+# Ordered list of (version, statements). Tables created from scratch by
+# `Base.metadata.create_all()` already match the models, so these only need to
+# bring databases created by an older version of the app up to date.
+# Append new migrations to the end; never edit or reorder applied ones.
+# Statements that differ between databases can be given as a dict keyed by
+# dialect name ("sqlite", "postgresql"); other dialects skip that migration.
+MIGRATIONS: List[Tuple[str, Union[List[str], Dict[str, List[str]]]]] = [
+    ("0001_items_user_id", [
+        "ALTER TABLE items ADD COLUMN user_id VARCHAR",
+        "CREATE INDEX ix_items_user_id ON items (user_id)",
+    ]),
+    ("0002_items_version", [
+        "ALTER TABLE items ADD COLUMN version INTEGER NOT NULL DEFAULT 1",
+    ]),
+    ("0003_items_deleted_at", [
+        "ALTER TABLE items ADD COLUMN deleted_at TIMESTAMP",
+        "CREATE INDEX ix_items_deleted_at ON items (deleted_at)",
+    ]),
+    ("0004_items_position", [
+        "ALTER TABLE items ADD COLUMN position INTEGER",
+    ]),
+    # SQLite can't add a CHECK constraint to an existing table, so triggers enforce it
+    # there. Neither form checks existing rows, so over-long names already stored stay
+    # readable and only new writes are limited.
+    ("0005_items_name_length", {
+        "sqlite": [
+            "CREATE TRIGGER ck_items_name_length_insert BEFORE INSERT ON items "
+            "WHEN length(NEW.name) > 100 "
+            "BEGIN SELECT RAISE(ABORT, 'CHECK constraint failed: ck_items_name_length'); END",
+            "CREATE TRIGGER ck_items_name_length_update BEFORE UPDATE OF name ON items "
+            "WHEN length(NEW.name) > 100 "
+            "BEGIN SELECT RAISE(ABORT, 'CHECK constraint failed: ck_items_name_length'); END",
+        ],
+        "postgresql": [
+            "ALTER TABLE items ADD CONSTRAINT ck_items_name_length CHECK (length(name) <= 100) NOT VALID",
+        ],
+    }),
+    ("0006_items_kind", [
+        "ALTER TABLE items ADD COLUMN kind VARCHAR NOT NULL DEFAULT 'item'",
+        "CREATE INDEX ix_items_kind ON items (kind)",
+    ]),
+    # created_at drives the feed (ORDER BY created_at DESC LIMIT n), the list's
+    # from/to filter and the per-day stats range, which would otherwise scan the
+    # table. Text search needs no new index: ix_items_name already serves exact and
+    # prefix lookups, and the list's substring ILIKE '%text%' can't use a B-tree
+    # index at all (on PostgreSQL that would need a pg_trgm GIN index, which
+    # requires an extension, so it is left to deployments that need it).
+    ("0007_items_created_at", [
+        "CREATE INDEX ix_items_created_at ON items (created_at)",
+    ]),
+    ("0008_items_markdown", [
+        "ALTER TABLE items ADD COLUMN markdown BOOLEAN NOT NULL DEFAULT FALSE",
+    ]),
+    ("0009_items_completed", [
+        "ALTER TABLE items ADD COLUMN completed BOOLEAN NOT NULL DEFAULT FALSE",
+        "CREATE INDEX ix_items_completed ON items (completed)",
+    ]),
+    ("0010_items_bumped_at", [
+        "ALTER TABLE items ADD COLUMN bumped_at TIMESTAMP",
+    ]),
+]
+
+# The items table as first released, before any migration. `check_migrations`
+# replays every migration on top of it when there is no database to copy.
+BASELINE_SCHEMA = [
+    "CREATE TABLE items ("
+    "id INTEGER PRIMARY KEY, "
+    "name VARCHAR NOT NULL, "
+    "description VARCHAR, "
+    "created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP, "
+    "updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP)",
+    "CREATE INDEX ix_items_id ON items (id)",
+    "CREATE INDEX ix_items_name ON items (name)",
+    "CREATE INDEX ix_items_description ON items (description)",
+]
+
+def _ensure_migrations_table(engine: Engine) -> None:
+    with engine.begin() as conn:
+        conn.execute(text(
+            "CREATE TABLE IF NOT EXISTS schema_migrations ("
+            "version VARCHAR PRIMARY KEY, "
+            "applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP)"
+        ))
+
+def applied_migrations(engine: Engine) -> List[str]:
+    """Returns the versions already recorded in the schema_migrations table"""
+    _ensure_migrations_table(engine)
+    with engine.connect() as conn:
+        return [row[0] for row in conn.execute(text("SELECT version FROM schema_migrations ORDER BY version"))]
+
+def run_migrations(engine: Engine, fresh: bool = False) -> List[str]:
+    """
+    Applies any pending migrations, each in its own transaction, and returns the
+    versions that were applied.
+
+    When `fresh` is true the tables were just created from the current models, so
+    every migration is recorded as applied without executing its statements.
+    """
+    done = set(applied_migrations(engine))
+    applied = []
+    for version, statements in MIGRATIONS:
+        if version in done:
+            continue
+        if isinstance(statements, dict):
+            statements = statements.get(engine.dialect.name, [])
+        with engine.begin() as conn:
+            if not fresh:
+                for statement in statements:
+                    conn.execute(text(statement))
+            conn.execute(text("INSERT INTO schema_migrations (version) VALUES (:version)"), {"version": version})
+        applied.append(version)
+    return applied
+
+def recorded_migrations(engine: Engine) -> List[str]:
+    """Like applied_migrations, but without creating schema_migrations if it is missing"""
+    if "schema_migrations" not in inspect(engine).get_table_names():
+        return []
+    with engine.connect() as conn:
+        return [row[0] for row in conn.execute(text("SELECT version FROM schema_migrations ORDER BY version"))]
+
+def _copy_schema(source: Engine, target: Engine) -> None:
+    """
+    Recreates the tables of `source` in `target` with their columns, primary keys
+    and indexes. Defaults and constraints are left out since they may use SQL
+    the target database doesn't understand; migrations only need the structure.
+    """
+    inspector = inspect(source)
+    metadata = MetaData()
+    for name in inspector.get_table_names():
+        primary_key = set(inspector.get_pk_constraint(name).get("constrained_columns") or [])
+        table = Table(name, metadata, *[
+            Column(column["name"], column["type"], primary_key=column["name"] in primary_key)
+            for column in inspector.get_columns(name)
+        ])
+        for index in inspector.get_indexes(name):
+            if index["name"] and None not in index["column_names"]:
+                Index(index["name"], *[table.c[column] for column in index["column_names"]])
+    metadata.create_all(bind=target)
+
+def check_migrations(source: Engine, metadata: MetaData, table: Table) -> Tuple[List[str], List[str]]:
+    """
+    Dry run of the migrations that `source` is missing, for CI. The schema of
+    `source` (or BASELINE_SCHEMA when it has no items table yet, which replays
+    every migration) is copied into a throwaway in-memory SQLite database, and the
+    pending migrations are applied there, using their "sqlite" statements. `source`
+    itself is only read.
+
+    Returns the versions that were applied and the problems `verify_schema` finds
+    with `table` afterwards. A migration whose SQL fails raises its database error.
+    """
+    scratch = create_engine("sqlite://", poolclass=StaticPool)
+    try:
+        if has_existing_schema(source):
+            _copy_schema(source, scratch)
+            done = recorded_migrations(source)
+        else:
+            with scratch.begin() as conn:
+                for statement in BASELINE_SCHEMA:
+                    conn.execute(text(statement))
+            done = []
+        _ensure_migrations_table(scratch)
+        with scratch.begin() as conn:
+            for version in done:
+                conn.execute(text("INSERT INTO schema_migrations (version) VALUES (:version)"), {"version": version})
+        # As in init_db, tables added since are created from the models first
+        metadata.create_all(bind=scratch)
+        applied = run_migrations(scratch)
+        return applied, verify_schema(scratch, table)
+    finally:
+        scratch.dispose()
+
+def has_existing_schema(engine: Engine) -> bool:
+    """True if the items table already exists, i.e. this is not a brand-new database"""
+    return "items" in inspect(engine).get_table_names()
+
+def _python_type(column_type):
+    try:
+        return column_type.python_type
+    except NotImplementedError:
+        return None
+
+def verify_schema(engine: Engine, table: Table) -> List[str]:
+    """
+    Compares a table in the database with its model and returns a description of
+    each problem: the table or a column being missing, or a column whose type
+    stores a different kind of value (e.g. text where an integer is expected).
+    An empty list means the schema matches.
+    """
+    inspector = inspect(engine)
+    if table.name not in inspector.get_table_names():
+        return [f"table {table.name!r} does not exist"]
+    actual = {column["name"]: column["type"] for column in inspector.get_columns(table.name)}
+    problems = []
+    for column in table.columns:
+        if column.name not in actual:
+            problems.append(f"{table.name}.{column.name} is missing")
+            continue
+        expected_type = _python_type(column.type)
+        found_type = _python_type(actual[column.name])
+        if expected_type and found_type and expected_type is not found_type:
+            problems.append(f"{table.name}.{column.name} has type {actual[column.name]}, expected {column.type}")
+    return problems
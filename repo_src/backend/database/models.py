@@ -1,14 +1,87 @@
-from sqlalchemy import Column, Integer, String, DateTime
+from sqlalchemy import Boolean, CheckConstraint, Column, Integer, String, DateTime, Text, false
+from sqlalchemy.orm import validates
 from sqlalchemy.sql import func # for server_default=func.now()
 from repo_src.backend.database.connection import Base
+from repo_src.backend.data.validators import ITEM_NAME_MAX_LENGTH, clean_item_name
+
+# Name of the CHECK constraint on items.name; errors mentioning it are reported as "name too long"
+NAME_LENGTH_CONSTRAINT = "ck_items_name_length"
 
 class Item(Base):
     __tablename__ = "items"
+    __table_args__ = (
+        CheckConstraint(f"length(name) <= {ITEM_NAME_MAX_LENGTH}", name=NAME_LENGTH_CONSTRAINT),
+    )
 
     id = Column(Integer, primary_key=True, index=True, autoincrement=True)
     name = Column(String, index=True, nullable=False)
     description = Column(String, index=True, nullable=True)
+    # Owner of the item; NULL for items created in single-user mode
+    user_id = Column(String, index=True, nullable=True)
+    # Incremented on every update for optimistic concurrency control
+    version = Column(Integer, nullable=False, default=1, server_default="1")
+    # One of the ItemKind values in data/schemas.py
+    kind = Column(String, nullable=False, default="item", server_default="item", index=True)
+    # Whether the description is Markdown, rendered to HTML when MARKDOWN_ENABLED is set
+    markdown = Column(Boolean, nullable=False, default=False, server_default=false())
+    # Ticked off by the user; completed items can be hidden from the list
+    completed = Column(Boolean, nullable=False, default=False, server_default=false(), index=True)
+    # Manual sort order set by the reorder endpoint; NULL items sort after positioned ones
+    position = Column(Integer, nullable=True)
     
     # Timestamps
+    created_at = Column(DateTime(timezone=True), server_default=func.now(), index=True)
+    updated_at = Column(DateTime(timezone=True), onupdate=func.now(), server_default=func.now()) # server_default for initial creation
+    # Set by the bump endpoint to move the item to the top of newest-first lists
+    # without touching created_at; NULL until the item is first bumped
+    bumped_at = Column(DateTime(timezone=True), nullable=True)
+    # Set when the item is moved to the trash; NULL for live items
+    deleted_at = Column(DateTime(timezone=True), nullable=True, index=True) 
+
+    def __repr__(self) -> str:
+        return f"<Item id={self.id} name={self.name!r} version={self.version}>"
+
+    @validates("name")
+    def validate_name(self, key, name):
+        """
+        Apply the request schemas' name rules to every name set through the ORM,
+        so writes that don't come through the API (batch inserts, backup restores,
+        scripts) can't store a name the API would reject. Raises ValueError.
+        """
+        return clean_item_name(name)
+
+class ItemEvent(Base):
+    """
+    Append-only log of item changes, one row per mutation, so offline clients
+    can replay everything after the last `seq` they saw.
+    """
+    __tablename__ = "events"
+    # AUTOINCREMENT stops SQLite from reusing sequence numbers
+    __table_args__ = {"sqlite_autoincrement": True}
+
+    seq = Column(Integer, primary_key=True, autoincrement=True)
+    # created, updated, reordered, deleted or restored
+    kind = Column(String, nullable=False)
+    item_id = Column(Integer, index=True, nullable=False)
+    user_id = Column(String, index=True, nullable=True)
+    # JSON snapshot of the item after the change
+    payload = Column(Text, nullable=False)
     created_at = Column(DateTime(timezone=True), server_default=func.now())
-    updated_at = Column(DateTime(timezone=True), onupdate=func.now(), server_default=func.now()) # server_default for initial creation 
\ No newline at end of file
+
+    def __repr__(self) -> str:
+        return f"<ItemEvent seq={self.seq} kind={self.kind} item_id={self.item_id}>"
+
+class Setting(Base):
+    """
+    A UI preference stored as JSON under one of the keys in data/schemas.Settings.
+    `user_id` is empty for deployment-wide settings (single-user mode).
+    """
+    __tablename__ = "settings"
+
+    user_id = Column(String, primary_key=True, default="")
+    key = Column(String, primary_key=True)
+    value = Column(Text, nullable=False)
+    updated_at = Column(DateTime(timezone=True), server_default=func.now(), onupdate=func.now())
+
+    def __repr__(self) -> str:
+        return f"<Setting key={self.key} user_id={self.user_id!r}>"
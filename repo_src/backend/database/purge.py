@@ -0,0 +1,43 @@
+import asyncio
+from datetime import datetime, timedelta, timezone
+
+from sqlalchemy.orm import Session
+
+from repo_src.backend.database.connection import SessionLocal
+from repo_src.backend.database.models import Item
+
+def purge_trashed_items(db: Session, retention_days: int) -> int:
+    """
+    Permanently delete items that have been in the trash for more than
+    `retention_days`, and return how many were deleted. Their change log events
+    are kept.
+    """
+    # deleted_at is stored as naive UTC
+    cutoff = datetime.now(timezone.utc).replace(tzinfo=None) - timedelta(days=retention_days)
+    purged = (
+        db.query(Item)
+        .filter(Item.deleted_at.isnot(None), Item.deleted_at < cutoff)
+        .delete(synchronize_session=False)
+    )
+    db.commit()
+    return purged
+
+def purge_once(retention_days: int) -> int:
+    db = SessionLocal()
+    try:
+        return purge_trashed_items(db, retention_days)
+    finally:
+        db.close()
+
+async def purge_loop(interval_hours: float, retention_days: int):
+    """Purges the trash every `interval_hours` until cancelled, starting right away"""
+    while True:
+        try:
+            # The delete blocks on the driver, so keep it off the event loop
+            purged = await asyncio.to_thread(purge_once, retention_days)
+            if purged:
+                print(f"Purged {purged} items trashed more than {retention_days} days ago")
+        except Exception as e:
+            # A failed pass is retried on the next interval rather than stopping the task
+            print(f"Trash purge failed: {e!r}")
+        await asyncio.sleep(interval_hours * 3600)
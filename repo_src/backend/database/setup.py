@@ -1,30 +1,156 @@
-from repo_src.backend.database.connection import engine, Base
+from repo_src.backend.config import dangerous_operations_enabled, env_bool
+from repo_src.backend.database.connection import engine, Base, DATABASE_URL, SessionLocal, redact_db_url
+from repo_src.backend.database.migrations import check_migrations, has_existing_schema, run_migrations, verify_schema
 # Import all models here so Base has them registered
 from repo_src.backend.database import models # noqa Ensures models.py is loaded and Item model is registered with Base
 
+class NoModelsRegisteredError(RuntimeError):
+    """Raised when init_db finds no models on Base, so no tables would be created"""
+
+class SchemaMismatchError(RuntimeError):
+    """Raised at startup when the items table doesn't match the model and DATABASE_SCHEMA_STRICT is set"""
+
+class DangerousOperationsDisabledError(RuntimeError):
+    """Raised when a destructive operation is attempted without DANGEROUS_OPERATIONS set"""
+
 def init_db():
     """
     Initializes the database by creating all tables defined in the models
-    that inherit from Base. This is typically called on application startup.
-    In a production environment with an existing database, migrations (e.g., Alembic)
-    would be used instead of directly calling create_all().
+    that inherit from Base, then applies any pending migrations from
+    `database/migrations.py` to bring older databases up to date.
+    This is typically called on application startup.
     """
-    print(f"Initializing database at {engine.url} and creating tables if they don't exist...")
-    Base.metadata.create_all(bind=engine)
+    print(f"Initializing database at {redact_db_url(DATABASE_URL)} and creating tables if they don't exist...")
+    if not Base.metadata.tables:
+        # create_all() would silently do nothing and every query would later fail with "no such table"
+        message = (
+            "No database models are registered on Base, so no tables can be created. "
+            "Make sure repo_src/backend/database/models.py exists and is imported in database/setup.py."
+        )
+        print(f"Error: {message}")
+        raise NoModelsRegisteredError(message)
+    try:
+        fresh = not has_existing_schema(engine)
+        Base.metadata.create_all(bind=engine)
+        applied = run_migrations(engine, fresh=fresh)
+    except Exception as e:
+        print(f"Failed to initialize database at {redact_db_url(DATABASE_URL)}: {e}")
+        raise
     print("Database tables checked/created.")
+    if applied and not fresh:
+        print(f"Applied migrations: {', '.join(applied)}")
+    check_schema()
+
+def check_schema():
+    """
+    Reports differences between the items table and the Item model, so a stale
+    database fails with a clear message at startup instead of with errors on the
+    first request. With DATABASE_SCHEMA_STRICT set, a mismatch stops startup.
+    """
+    problems = verify_schema(engine, models.Item.__table__)
+    if not problems:
+        return
+    message = (
+        f"The database at {redact_db_url(DATABASE_URL)} does not match the models: {'; '.join(problems)}. "
+        "Add a migration in database/migrations.py for each model change."
+    )
+    print(f"Error: {message}")
+    if env_bool("DATABASE_SCHEMA_STRICT"):
+        raise SchemaMismatchError(message)
 
 def drop_db():
     """
     Drops all tables from the database. Use with caution, primarily for testing
     or resetting the development environment.
     """
-    print(f"Dropping all tables from the database at {engine.url}...")
+    print(f"Dropping all tables from the database at {redact_db_url(DATABASE_URL)}...")
     Base.metadata.drop_all(bind=engine)
     print("Database tables dropped.")
 
+def clear_all_items() -> int:
+    """
+    Deletes every row from the items table, including trashed items, and returns
+    the number deleted. Only allowed when DANGEROUS_OPERATIONS is set, so that a
+    production instance can't be wiped by accident.
+    """
+    if not dangerous_operations_enabled():
+        raise DangerousOperationsDisabledError("Clearing all items requires DANGEROUS_OPERATIONS=true")
+    db = SessionLocal()
+    try:
+        deleted = db.query(models.Item).delete()
+        db.commit()
+    except Exception:
+        db.rollback()
+        raise
+    finally:
+        db.close()
+    return deleted
+
+def check_pending_migrations() -> int:
+    """
+    Applies the database's pending migrations to a throwaway copy of its schema
+    and reports the outcome, without changing the database. Returns the exit code.
+    """
+    print(f"Checking migrations against {redact_db_url(DATABASE_URL)} (dry run on an in-memory copy)...")
+    try:
+        applied, problems = check_migrations(engine, Base.metadata, models.Item.__table__)
+    except Exception as e:
+        print(f"Error: migration failed: {e}")
+        return 1
+    if applied:
+        print(f"Migrations that would be applied: {', '.join(applied)}")
+    else:
+        print("No pending migrations")
+    if problems:
+        print(f"Error: schema after migrating does not match the models: {'; '.join(problems)}")
+        return 1
+    print("Migration check passed")
+    return 0
+
+def main(argv=None) -> int:
+    """Command line entrypoint for database administration tasks"""
+    import argparse
+    from pathlib import Path
+    from repo_src.backend.database.backup import BackupError, backup_to_file, restore_from_file
+
+    parser = argparse.ArgumentParser(description="Database administration for the backend")
+    commands = parser.add_subparsers(dest="command")
+    commands.add_parser("init", help="Create tables and apply pending migrations (default)")
+    commands.add_parser("drop", help="Drop all tables")
+    migrate = commands.add_parser("migrate", help="Apply pending migrations (same as init)")
+    migrate.add_argument("--check", action="store_true", help="Only check that pending migrations apply cleanly, without changing the database")
+    backup = commands.add_parser("backup", help="Write all items to a JSON file")
+    backup.add_argument("path", type=Path)
+    restore = commands.add_parser("restore", help="Load items from a JSON backup file")
+    restore.add_argument("path", type=Path)
+    restore.add_argument("--force", action="store_true", help="Replace existing items instead of refusing")
+    clear = commands.add_parser("clear", help="Delete all items (requires DANGEROUS_OPERATIONS=true)")
+    clear.add_argument("--yes", action="store_true", help="Confirm that every item should be deleted")
+    args = parser.parse_args(argv)
+
+    if args.command == "drop":
+        drop_db()
+        return 0
+    if args.command == "migrate" and args.check:
+        return check_pending_migrations()
+    if args.command == "clear" and not args.yes:
+        print("Refusing to delete all items without --yes")
+        return 1
+    init_db()
+    try:
+        if args.command == "backup":
+            print(f"Backed up {backup_to_file(args.path)} items to {args.path}")
+        elif args.command == "restore":
+            print(f"Restored {restore_from_file(args.path, force=args.force)} items from {args.path}")
+        elif args.command == "clear":
+            print(f"Deleted {clear_all_items()} items")
+    except (BackupError, DangerousOperationsDisabledError) as e:
+        print(f"Error: {e}")
+        return 1
+    return 0
+
 if __name__ == "__main__":
-    # This allows running `python -m repo_src.backend.database.setup` (from project root)
-    # or `python -m backend.database.setup` (from repo_src)
-    # Consider adding command line arguments for init/drop actions.
+    # This allows running `python -m repo_src.backend.database.setup [command]` (from project root)
+    # or `python -m backend.database.setup [command]` (from repo_src)
     print("Running database setup script...")
-    init_db() # Example: Initialize DB by default if script is run directly. 
\ No newline at end of file
+    raise SystemExit(main()) 
\ No newline at end of file
@@ -0,0 +1,11 @@
+from fastapi import Request
+from typing import Optional
+
+def get_current_user_id(request: Request) -> Optional[str]:
+    """
+    Returns the id of the authenticated user for this request, if any.
+
+    Authentication middleware is expected to set `request.state.user_id`. When no
+    user is present the backend runs in single-user mode and data access is global.
+    """
+    return getattr(request.state, "user_id", None)
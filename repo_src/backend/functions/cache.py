@@ -0,0 +1,36 @@
+import threading
+import time
+from typing import Any, Callable, Dict, Hashable, Tuple
+
+class TtlCache:
+    """
+    A small in-process cache with a fixed time-to-live.
+
+    Loads happen under a lock, so concurrent readers of a missing or expired key
+    wait for a single load instead of each hitting the database. A TTL of 0
+    disables caching and every call goes straight to the loader.
+    """
+
+    def __init__(self, ttl_ms: int):
+        self.ttl = ttl_ms / 1000
+        self._entries: Dict[Hashable, Tuple[float, Any]] = {}
+        self._lock = threading.Lock()
+
+    @property
+    def enabled(self) -> bool:
+        return self.ttl > 0
+
+    def get_or_load(self, key: Hashable, loader: Callable[[], Any]) -> Any:
+        if not self.enabled:
+            return loader()
+        with self._lock:
+            entry = self._entries.get(key)
+            if entry is not None and time.monotonic() - entry[0] < self.ttl:
+                return entry[1]
+            value = loader()
+            self._entries[key] = (time.monotonic(), value)
+            return value
+
+    def invalidate(self) -> None:
+        with self._lock:
+            self._entries.clear()
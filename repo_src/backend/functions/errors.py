@@ -0,0 +1,71 @@
+from fastapi import Request, status
+from fastapi.responses import JSONResponse
+from sqlalchemy.exc import OperationalError
+from sqlalchemy.exc import TimeoutError as PoolTimeoutError
+
+from repo_src.backend.middleware.request_id import get_request_id
+
+SERVER_BUSY_MESSAGE = "Server busy, please try again shortly"
+# Seconds clients are asked to wait (Retry-After) before retrying a request that
+# couldn't get a database connection
+SERVER_BUSY_RETRY_AFTER = 1
+DATABASE_UNAVAILABLE_MESSAGE = "Database temporarily unavailable"
+
+class ApiError(Exception):
+    """
+    An error raised by a REST handler, rendered by `api_error_handler` as
+    `{"error": {"code": ..., "message": ...}}` with `status_code`.
+    """
+    status_code = status.HTTP_500_INTERNAL_SERVER_ERROR
+    code = "internal_error"
+
+    def __init__(self, message: str):
+        super().__init__(message)
+        self.message = message
+
+class BadRequestError(ApiError):
+    status_code = status.HTTP_400_BAD_REQUEST
+    code = "bad_request"
+
+class UnauthorizedError(ApiError):
+    status_code = status.HTTP_401_UNAUTHORIZED
+    code = "unauthorized"
+
+class ForbiddenError(ApiError):
+    status_code = status.HTTP_403_FORBIDDEN
+    code = "forbidden"
+
+class NotFoundError(ApiError):
+    status_code = status.HTTP_404_NOT_FOUND
+    code = "not_found"
+
+class ConflictError(ApiError):
+    status_code = status.HTTP_409_CONFLICT
+    code = "conflict"
+
+async def api_error_handler(request: Request, exc: ApiError) -> JSONResponse:
+    return JSONResponse(
+        status_code=exc.status_code,
+        content={"error": {"code": exc.code, "message": exc.message}},
+    )
+
+async def pool_timeout_handler(request: Request, exc: PoolTimeoutError) -> JSONResponse:
+    """
+    Report an exhausted connection pool as a temporary 503 rather than a 500. The
+    `server_busy` code and Retry-After header tell clients to back off and retry,
+    unlike the outage response from `database_unavailable_handler`.
+    """
+    print(f"[{get_request_id()}] Database connection pool timed out on {request.method} {request.url.path}")
+    return JSONResponse(
+        status_code=status.HTTP_503_SERVICE_UNAVAILABLE,
+        content={"detail": SERVER_BUSY_MESSAGE, "code": "server_busy"},
+        headers={"Retry-After": str(SERVER_BUSY_RETRY_AFTER)},
+    )
+
+async def database_unavailable_handler(request: Request, exc: OperationalError) -> JSONResponse:
+    """
+    Report database connection failures as 503 so clients can tell an outage
+    apart from a bug (500) or an empty result.
+    """
+    print(f"[{get_request_id()}] Database unavailable on {request.method} {request.url.path}: {exc.orig!r}")
+    return JSONResponse(status_code=status.HTTP_503_SERVICE_UNAVAILABLE, content={"detail": DATABASE_UNAVAILABLE_MESSAGE})
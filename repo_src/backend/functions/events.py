@@ -0,0 +1,87 @@
+import json
+from fastapi import APIRouter, Depends, Query
+from sqlalchemy.orm import Session
+from typing import List, Optional
+
+from repo_src.backend.database.connection import get_db
+from repo_src.backend.database.models import Item, ItemEvent
+from repo_src.backend.data.schemas import ItemEventResponse, ItemResponse
+from repo_src.backend.functions.auth import get_current_user_id
+from repo_src.backend.functions.errors import NotFoundError
+
+router = APIRouter(prefix="/api/events", tags=["events"])
+
+DEFAULT_EVENTS_LIMIT = 100
+MAX_EVENTS_LIMIT = 1000
+
+def record_event(db: Session, kind: str, db_item: Item) -> None:
+    """
+    Append an event for a change to `db_item`. Call it before committing so the
+    event is written in the same transaction as the change itself.
+    """
+    db.flush()
+    # Load server-generated values (id, timestamps) for the snapshot
+    db.refresh(db_item)
+    db.add(ItemEvent(
+        kind=kind,
+        item_id=db_item.id,
+        user_id=db_item.user_id,
+        payload=ItemResponse.model_validate(db_item).model_dump_json(),
+    ))
+
+def event_response(event: ItemEvent) -> ItemEventResponse:
+    return ItemEventResponse(
+        seq=event.seq,
+        kind=event.kind,
+        item_id=event.item_id,
+        payload=json.loads(event.payload),
+        created_at=event.created_at,
+    )
+
+def item_history(db: Session, user_id: Optional[str], item_id: int) -> List[ItemEvent]:
+    """
+    The events for one item, oldest first. An item that was only ever created has
+    no history yet, so a lone "created" event gives an empty list.
+    """
+    query = db.query(ItemEvent).filter(ItemEvent.item_id == item_id)
+    if user_id is not None:
+        query = query.filter(ItemEvent.user_id == user_id)
+    events = query.order_by(ItemEvent.seq).all()
+    if all(event.kind == "created" for event in events):
+        return []
+    return events
+
+@router.get("/", response_model=List[ItemEventResponse])
+def read_events_since(
+    since: int = Query(0, ge=0, description="Return events with a seq greater than this"),
+    limit: int = Query(DEFAULT_EVENTS_LIMIT, ge=1, le=MAX_EVENTS_LIMIT),
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    Item change log in `seq` order. Clients store the last `seq` they applied and
+    pass it as `since` to resume; an empty list means they are up to date.
+    """
+    query = db.query(ItemEvent).filter(ItemEvent.seq > since)
+    if user_id is not None:
+        query = query.filter(ItemEvent.user_id == user_id)
+    events = query.order_by(ItemEvent.seq).limit(limit).all()
+    return [event_response(event) for event in events]
+
+@router.get("/items/{item_id}", response_model=List[ItemEventResponse], responses={404: {"description": "No such item and no recorded events"}})
+def read_item_history(item_id: int, db: Session = Depends(get_db), user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Timeline of one item's changes (updates, deletes, restores, reorders), each
+    with the item's state after it, oldest first. Events outlive the item, so a
+    permanently deleted item still has its history. Empty when the item has not
+    changed since it was created.
+    """
+    events = item_history(db, user_id, item_id)
+    if not events:
+        # Trashed items count as existing: their history is just empty
+        query = db.query(Item).filter(Item.id == item_id)
+        if user_id is not None:
+            query = query.filter(Item.user_id == user_id)
+        if query.first() is None:
+            raise NotFoundError("Item not found")
+    return [event_response(event) for event in events]
@@ -0,0 +1,90 @@
+import csv
+import io
+import json
+from fastapi import APIRouter, Depends
+from fastapi.responses import StreamingResponse
+from sqlalchemy.orm import Session
+from typing import Iterator, Optional
+
+from repo_src.backend.database.connection import SessionLocal
+from repo_src.backend.database.models import Item
+from repo_src.backend.data.schemas import EXPORT_SCHEMA_VERSION, ItemResponse
+from repo_src.backend.functions.auth import get_current_user_id
+from repo_src.backend.functions.items import scoped_items
+
+router = APIRouter(prefix="/api", tags=["export"])
+
+EXPORT_BATCH_SIZE = 500
+CSV_COLUMNS = ["id", "name", "description", "kind", "markdown", "completed", "user_id", "version", "position", "created_at", "updated_at"]
+
+def get_items_stream(db: Session, user_id: Optional[str], batch_size: int = EXPORT_BATCH_SIZE) -> Iterator[ItemResponse]:
+    """
+    Yield every live item in id order without loading the whole table. Rows are
+    fetched in keyset-paginated batches, so memory use is bounded by `batch_size`
+    and each batch is an independent query.
+    """
+    last_id = 0
+    while True:
+        batch = scoped_items(db, user_id).filter(Item.id > last_id).order_by(Item.id).limit(batch_size).all()
+        for item in batch:
+            yield ItemResponse.model_validate(item)
+        if len(batch) < batch_size:
+            return
+        last_id = batch[-1].id
+        db.expunge_all()  # Drop the previous batch from the identity map
+
+def stream_items(user_id: Optional[str]) -> Iterator[ItemResponse]:
+    """
+    get_items_stream on a session of its own, closed when the stream ends. A
+    StreamingResponse body keeps reading after the endpoint returns, when a
+    get_db session may already have been closed by dependency teardown.
+    """
+    db = SessionLocal()
+    try:
+        yield from get_items_stream(db, user_id, EXPORT_BATCH_SIZE)
+    finally:
+        db.close()
+
+def download_headers(filename: str) -> dict:
+    return {"Content-Disposition": f'attachment; filename="{filename}"'}
+
+@router.get("/export.json")
+def export_items_json(user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Download all items as `{"schema_version": ..., "items": [...]}`, streamed row
+    by row. The file can be loaded with the `restore` database command.
+    """
+    def body() -> Iterator[str]:
+        yield f'{{"schema_version": {EXPORT_SCHEMA_VERSION}, "items": ['
+        for index, item in enumerate(stream_items(user_id)):
+            yield ("," if index else "") + json.dumps(item.model_dump(mode="json"))
+        yield "]}"
+    return StreamingResponse(body(), media_type="application/json", headers=download_headers("items.json"))
+
+@router.get("/export.jsonl")
+def export_items_jsonl(user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Download all items as JSON Lines: one item object per line, streamed row by
+    row, so clients can process each item as it arrives instead of parsing one
+    large array. Lines carry no schema version; use export.json for restores.
+    """
+    def body() -> Iterator[str]:
+        for item in stream_items(user_id):
+            yield json.dumps(item.model_dump(mode="json")) + "\n"
+    return StreamingResponse(body(), media_type="application/x-ndjson", headers=download_headers("items.jsonl"))
+
+@router.get("/export.csv")
+def export_items_csv(user_id: Optional[str] = Depends(get_current_user_id)):
+    """Download all items as CSV, streamed row by row"""
+    def body() -> Iterator[str]:
+        buffer = io.StringIO()
+        writer = csv.writer(buffer)
+        writer.writerow(CSV_COLUMNS)
+        for item in stream_items(user_id):
+            row = item.model_dump(mode="json")
+            writer.writerow([row[column] for column in CSV_COLUMNS])
+            yield buffer.getvalue()
+            buffer.seek(0)
+            buffer.truncate()
+        yield buffer.getvalue()
+    return StreamingResponse(body(), media_type="text/csv", headers=download_headers("items.csv"))
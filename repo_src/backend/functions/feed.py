@@ -0,0 +1,57 @@
+from datetime import datetime, timezone
+from email.utils import format_datetime
+from fastapi import APIRouter, Depends, Query, Request, Response
+from sqlalchemy.orm import Session
+from typing import List, Optional
+from xml.sax.saxutils import escape
+
+from repo_src.backend.database.connection import get_db
+from repo_src.backend.database.models import Item
+from repo_src.backend.functions.auth import get_current_user_id
+from repo_src.backend.functions.items import scoped_items
+
+router = APIRouter(tags=["feed"])
+
+DEFAULT_FEED_SIZE = 20
+MAX_FEED_SIZE = 100
+
+def rfc822(value: datetime) -> str:
+    """Format a timestamp for RSS; naive database timestamps are treated as UTC"""
+    if value.tzinfo is None:
+        value = value.replace(tzinfo=timezone.utc)
+    return format_datetime(value)
+
+def render_rss(items: List[Item], link: str) -> str:
+    """Render items as an RSS 2.0 document, escaping all text content"""
+    entries = []
+    for item in items:
+        entries.append(
+            "    <item>\n"
+            f"      <title>{escape(item.name)}</title>\n"
+            f"      <description>{escape(item.description or '')}</description>\n"
+            f"      <guid isPermaLink=\"false\">item-{item.id}</guid>\n"
+            f"      <pubDate>{rfc822(item.created_at)}</pubDate>\n"
+            "    </item>\n"
+        )
+    return (
+        '<?xml version="1.0" encoding="UTF-8"?>\n'
+        '<rss version="2.0">\n'
+        "  <channel>\n"
+        "    <title>Recent items</title>\n"
+        f"    <link>{escape(link)}</link>\n"
+        "    <description>The most recently created items</description>\n"
+        + "".join(entries) +
+        "  </channel>\n"
+        "</rss>\n"
+    )
+
+@router.get("/feed.xml", response_class=Response, responses={200: {"content": {"application/rss+xml": {}}}})
+def items_feed(
+    request: Request,
+    limit: int = Query(DEFAULT_FEED_SIZE, ge=1, le=MAX_FEED_SIZE),
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """RSS 2.0 feed of the most recently created items"""
+    items = scoped_items(db, user_id).order_by(Item.created_at.desc()).limit(limit).all()
+    return Response(content=render_rss(items, str(request.base_url)), media_type="application/rss+xml")
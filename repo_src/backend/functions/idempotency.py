@@ -0,0 +1,29 @@
+import threading
+import time
+from typing import Dict, Hashable, Optional, Tuple
+
+class IdempotencyStore:
+    """
+    Remembers which item was created for a client-supplied idempotency key so a
+    retried create returns the original item instead of inserting a duplicate.
+    Keys are kept in memory and forgotten after `ttl_seconds`.
+    """
+
+    def __init__(self, ttl_seconds: int):
+        self.ttl = ttl_seconds
+        self._keys: Dict[Hashable, Tuple[float, int]] = {}
+        # Held for the whole lookup-then-create so concurrent retries can't both insert
+        self.lock = threading.Lock()
+
+    def get(self, key: Hashable) -> Optional[int]:
+        self._expire()
+        entry = self._keys.get(key)
+        return entry[1] if entry else None
+
+    def remember(self, key: Hashable, item_id: int) -> None:
+        self._keys[key] = (time.monotonic(), item_id)
+
+    def _expire(self) -> None:
+        cutoff = time.monotonic() - self.ttl
+        for key in [k for k, (seen, _) in self._keys.items() if seen < cutoff]:
+            del self._keys[key]
@@ -1,62 +1,627 @@
-from fastapi import APIRouter, Depends, HTTPException, status
+from fastapi import APIRouter, Body, Depends, Header, Query, Request, Response, status
+from fastapi.responses import JSONResponse
+from pydantic import ValidationError
+from datetime import datetime, timezone
+from sqlalchemy import func, or_
 from sqlalchemy.orm import Session
-from typing import List
+from contextlib import contextmanager
+from sqlalchemy.exc import IntegrityError
+from typing import Any, List, Optional
+import hashlib
+import threading
 
-from repo_src.backend.database.connection import get_db
-from repo_src.backend.database.models import Item
-from repo_src.backend.data.schemas import ItemCreate, ItemResponse, ItemUpdate
+from repo_src.backend.database.connection import TransactionRoute, after_commit, get_db, get_read_db, get_transaction, hold_until_done
+from repo_src.backend.database.models import NAME_LENGTH_CONSTRAINT, Item, ItemEvent
+from repo_src.backend.data.schemas import BatchCreateResponse, BatchItemResult, DeleteFilteredRequest, DeleteIdsRequest, DeleteIdsResponse, DeleteMatchingRequest, DeleteMatchingResponse, ItemCreate, ItemDiff, ItemFilter, ItemKind, ItemPatch, ItemResponse, ItemUpdate, Page, ReorderRequest
+from repo_src.backend.functions.auth import get_current_user_id
+from repo_src.backend.functions.cache import TtlCache
+from repo_src.backend.functions.idempotency import IdempotencyStore
+from repo_src.backend.functions.events import record_event
+from repo_src.backend.functions.errors import BadRequestError, ConflictError, ForbiddenError, NotFoundError
+from repo_src.backend.config import dangerous_operations_enabled, env_int, env_str
+from repo_src.backend.data.validators import NAME_TOO_LONG_MESSAGE, clean_item_name
+from repo_src.backend.functions.validation import combined_message, field_errors
 
 router = APIRouter(
     prefix="/api/items",
     tags=["items"],
     responses={404: {"description": "Item not found"}},
+    # Endpoints on get_transaction commit when they return, before the response is sent
+    route_class=TransactionRoute,
 )
 
-@router.post("/", response_model=ItemResponse, status_code=status.HTTP_201_CREATED)
-def create_item(item: ItemCreate, db: Session = Depends(get_db)):
-    """Create a new item in the database"""
-    db_item = Item(**item.dict())
-    db.add(db_item)
-    db.commit()
+# Documented on mutating routes; only returned when API_KEY is configured
+UNAUTHORIZED_RESPONSE = {401: {"description": "Invalid or missing API key"}}
+
+# Optional cache for list pages; disabled unless ITEMS_CACHE_TTL_MS > 0. List pages
+# are keyed by their ETag, so a write committed by any worker changes the key; every
+# endpoint that changes items still calls items_cache.invalidate() to drop old
+# entries (and refresh /recent, which has no ETag) in this process.
+items_cache = TtlCache(env_int("ITEMS_CACHE_TTL_MS", 0))
+
+# Idempotency-Key values seen on create, so client retries don't insert duplicates
+idempotency_keys = IdempotencyStore(env_int("IDEMPOTENCY_TTL_SECONDS", 300))
+
+# Optional cap on live (non-trashed) items across all users; 0 or unset means no cap.
+# The lock makes the count check and the insert that follows it atomic within this process.
+MAX_TOTAL_ITEMS = env_int("MAX_TOTAL_ITEMS", 0)
+quota_lock = threading.Lock()
+ITEM_LIMIT_MESSAGE = "Item limit reached"
+
+def remaining_quota(db: Session) -> Optional[int]:
+    """Number of items that may still be created, or None when no cap is configured"""
+    if MAX_TOTAL_ITEMS <= 0:
+        return None
+    live = db.query(func.count(Item.id)).filter(Item.deleted_at.is_(None)).scalar()
+    return max(0, MAX_TOTAL_ITEMS - live)
+
+def check_quota(db: Session) -> None:
+    """Raise 409 if creating one more item would exceed MAX_TOTAL_ITEMS"""
+    if remaining_quota(db) == 0:
+        raise ConflictError(f"{ITEM_LIMIT_MESSAGE} ({MAX_TOTAL_ITEMS})")
+
+def scoped_items(db: Session, user_id: Optional[str], deleted: Optional[bool] = False):
+    """
+    Query items visible to the given user, or all items in single-user mode.
+    By default soft-deleted items are excluded; `deleted=True` returns only the
+    trashed items and `deleted=None` returns both.
+    """
+    query = db.query(Item)
+    if user_id is not None:
+        query = query.filter(Item.user_id == user_id)
+    if deleted is None:
+        return query
+    if deleted:
+        return query.filter(Item.deleted_at.isnot(None))
+    return query.filter(Item.deleted_at.is_(None))
+
+@contextmanager
+def name_length_errors(db: Session):
+    """
+    Turn a violation of the items.name length constraint into the same 400
+    message the request schemas give, for writes that bypass them.
+    """
+    try:
+        yield
+    except IntegrityError as e:
+        db.rollback()
+        if NAME_LENGTH_CONSTRAINT in str(e.orig):
+            raise BadRequestError(NAME_TOO_LONG_MESSAGE) from e
+        raise
+
+# Manually positioned items first, in position order, then the rest by id
+LIST_ORDER = (Item.position.asc().nulls_last(), Item.id)
+# Most recently created or bumped first; bumping keeps the real creation time
+NEWEST_FIRST = (func.coalesce(Item.bumped_at, Item.created_at).desc(), Item.id.desc())
+
+@router.post(
+    "/",
+    response_model=ItemResponse,
+    status_code=status.HTTP_201_CREATED,
+    responses={409: {"description": "MAX_TOTAL_ITEMS has been reached"}, **UNAUTHORIZED_RESPONSE},
+)
+def create_item(
+    item: ItemCreate,
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+    idempotency_key: Optional[str] = Header(None),
+):
+    """
+    Create a new item in the database. If an `Idempotency-Key` header repeats a
+    recently used key, the item created by the first request is returned instead.
+    """
+    if idempotency_key is None:
+        return insert_item(db, item, user_id)
+
+    key = (user_id, idempotency_key)
+    with idempotency_keys.lock:
+        existing_id = idempotency_keys.get(key)
+        if existing_id is not None:
+            existing = db.query(Item).filter(Item.id == existing_id).first()
+            if existing is not None:
+                return existing
+        db_item = insert_item(db, item, user_id)
+        idempotency_keys.remember(key, db_item.id)
+        return db_item
+
+def insert_item(db: Session, item: ItemCreate, user_id: Optional[str]) -> Item:
+    """Insert a single item and return it with its generated fields loaded"""
+    db_item = Item(**item.dict(), user_id=user_id)
+    with quota_lock, name_length_errors(db):
+        check_quota(db)
+        db.add(db_item)
+        record_event(db, "created", db_item)
+        db.commit()
+    items_cache.invalidate()
     db.refresh(db_item)
     return db_item
 
-@router.get("/", response_model=List[ItemResponse])
-def read_items(skip: int = 0, limit: int = 100, db: Session = Depends(get_db)):
-    """Get a list of items from the database"""
-    items = db.query(Item).offset(skip).limit(limit).all()
-    return items
+@router.post(
+    "/batch",
+    response_model=BatchCreateResponse,
+    status_code=status.HTTP_207_MULTI_STATUS,
+    responses={
+        201: {"description": "All elements were inserted", "model": BatchCreateResponse},
+        207: {"description": "Some elements failed validation; see per-element results"},
+        **UNAUTHORIZED_RESPONSE,
+    },
+)
+def create_items_batch(
+    entries: List[Any] = Body(...),
+    db: Session = Depends(get_transaction),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    Create many items in one request. Each element is validated on its own;
+    invalid elements are reported and skipped, and the valid ones are inserted
+    together in a single transaction. An element with the same content as an
+    earlier one is reported as a duplicate and skipped. Elements beyond MAX_TOTAL_ITEMS are
+    reported as failed.
+    """
+    results = [BatchItemResult(index=index) for index in range(len(entries))]
+    valid = []
+    first_with_hash = {}
+    for index, entry in enumerate(entries):
+        try:
+            item = ItemCreate.model_validate(entry)
+        except ValidationError as e:
+            results[index].error = combined_message(field_errors(e.errors()))
+            continue
+        fingerprint = item.content_hash()
+        if fingerprint in first_with_hash:
+            results[index].error = f"Duplicate of element {first_with_hash[fingerprint]}"
+            continue
+        first_with_hash[fingerprint] = index
+        valid.append((index, Item(**item.dict(), user_id=user_id)))
+
+    hold_until_done(db, quota_lock)
+    remaining = remaining_quota(db)
+    pending = valid if remaining is None else valid[:remaining]
+    for index, _ in valid[len(pending):]:
+        results[index].error = ITEM_LIMIT_MESSAGE
+    with name_length_errors(db):
+        db.add_all([db_item for _, db_item in pending])
+        for _, db_item in pending:
+            record_event(db, "created", db_item)
+    after_commit(db, items_cache.invalidate)
+    for index, db_item in pending:
+        results[index].id = db_item.id
+
+    inserted = len(pending)
+    body = BatchCreateResponse(results=results, inserted=inserted, failed=len(entries) - inserted)
+    status_code = status.HTTP_201_CREATED if body.failed == 0 else status.HTTP_207_MULTI_STATUS
+    return JSONResponse(status_code=status_code, content=body.model_dump())
+
+@router.post(
+    "/delete-matching",
+    response_model=DeleteMatchingResponse,
+    responses={
+        400: {"description": "The pattern matches every item and force was not set"},
+        403: {"description": "DANGEROUS_OPERATIONS is not enabled"},
+        **UNAUTHORIZED_RESPONSE,
+    },
+)
+def delete_items_matching(request: DeleteMatchingRequest, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Permanently delete every item, trashed or not, whose name matches a SQL LIKE
+    pattern (`%` matches any text, `_` one character, and `\\` escapes either).
+    Only available when DANGEROUS_OPERATIONS is enabled. A pattern made only of
+    `%` would delete everything and is refused unless `force` is set.
+    Returns the number of items deleted.
+    """
+    if not dangerous_operations_enabled():
+        raise ForbiddenError("Bulk delete requires DANGEROUS_OPERATIONS=true")
+    if not request.pattern.strip("%") and not request.force:
+        raise BadRequestError("Pattern matches every item; set force to delete them all")
+
+    rows = scoped_items(db, user_id, deleted=None).filter(Item.name.like(request.pattern, escape="\\")).all()
+    for db_item in rows:
+        record_event(db, "deleted", db_item)
+        db.delete(db_item)
+    after_commit(db, items_cache.invalidate)
+    return DeleteMatchingResponse(deleted=len(rows))
+
+@router.delete(
+    "/",
+    response_model=DeleteMatchingResponse,
+    responses={
+        400: {"description": "The filter sets no condition and confirm was not set"},
+        **UNAUTHORIZED_RESPONSE,
+    },
+)
+def delete_items_filtered(request: DeleteFilteredRequest, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Move every live item matching the filter in the body to the trash, in one
+    transaction, and return how many were trashed. Takes the same conditions as
+    the list endpoint; a filter without any would trash everything and is refused
+    unless `confirm` is true.
+    """
+    if request.matches_everything() and not request.confirm:
+        raise BadRequestError("Filter matches every item; set confirm to delete them all")
+
+    rows = query_items(db, user_id, request).all()
+    for db_item in rows:
+        db_item.deleted_at = func.now()
+        record_event(db, "deleted", db_item)
+    after_commit(db, items_cache.invalidate)
+    return DeleteMatchingResponse(deleted=len(rows))
+
+@router.post(
+    "/reorder",
+    response_model=List[ItemResponse],
+    responses={400: {"description": "Duplicate or unknown ids in the order"}, **UNAUTHORIZED_RESPONSE},
+)
+def reorder_items(request: ReorderRequest, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Assign sequential positions to items following the given id order, in one
+    transaction. Every id must exist and appear only once.
+    """
+    if len(set(request.order)) != len(request.order):
+        raise BadRequestError("Order contains duplicate ids")
+    rows = {item.id: item for item in scoped_items(db, user_id).filter(Item.id.in_(request.order))}
+    missing = [item_id for item_id in request.order if item_id not in rows]
+    if missing:
+        raise BadRequestError(f"Unknown item ids: {missing}")
+
+    for position, item_id in enumerate(request.order):
+        rows[item_id].position = position
+        record_event(db, "reordered", rows[item_id])
+    after_commit(db, items_cache.invalidate)
+    return scoped_items(db, user_id).order_by(*LIST_ORDER).all()
+
+def escape_like(value: str) -> str:
+    """Escape LIKE wildcards so user text is matched literally (with escape="\\")"""
+    return value.replace("\\", "\\\\").replace("%", "\\%").replace("_", "\\_")
+
+def query_items(db: Session, user_id: Optional[str], item_filter: ItemFilter):
+    """The live items matching every condition set in `item_filter`"""
+    query = scoped_items(db, user_id)
+    if item_filter.text:
+        pattern = f"%{escape_like(item_filter.text)}%"
+        query = query.filter(or_(
+            Item.name.ilike(pattern, escape="\\"),
+            Item.description.ilike(pattern, escape="\\"),
+        ))
+    if item_filter.from_ is not None:
+        query = query.filter(Item.created_at >= as_naive_utc(item_filter.from_))
+    if item_filter.to is not None:
+        query = query.filter(Item.created_at <= as_naive_utc(item_filter.to))
+    if item_filter.kind is not None:
+        query = query.filter(Item.kind == item_filter.kind.value)
+    if item_filter.completed is not None:
+        query = query.filter(Item.completed == item_filter.completed)
+    return query
+
+def list_filter(
+    text: Optional[str] = Query(None, description="Only items whose name or description contains this text"),
+    from_: Optional[datetime] = Query(None, alias="from", description="Only items created at or after this time"),
+    to: Optional[datetime] = Query(None, description="Only items created at or before this time"),
+    kind: Optional[ItemKind] = Query(None, description="Only list items of this kind"),
+    completed: Optional[bool] = Query(None, description="Only list completed (true) or open (false) items"),
+) -> ItemFilter:
+    """Collect the list endpoint's filter query parameters into an ItemFilter"""
+    return ItemFilter(text=text, from_=from_, to=to, kind=kind, completed=completed)
+
+def items_list_etag(db: Session, user_id: Optional[str], page: int, per_page: int, item_filter: ItemFilter = ItemFilter()) -> str:
+    """
+    Compute a weak ETag for a page of the items list. Timestamps only have
+    second resolution, so it is built from change tokens instead: the matching
+    rows' count, highest id and summed versions (which move on every insert and
+    edit), and the latest event's seq (which also moves on reorders, deletes and
+    restores, whose writes don't bump versions).
+    """
+    count, latest_id, version_sum = query_items(db, user_id, item_filter).with_entities(
+        func.count(Item.id), func.max(Item.id), func.sum(Item.version)
+    ).one()
+    latest_event = db.query(func.max(ItemEvent.seq)).scalar()
+    fingerprint = f"{count}:{latest_id}:{version_sum}:{latest_event}:{page}:{per_page}:{item_filter!r}"
+    return f'W/"{hashlib.sha1(fingerprint.encode()).hexdigest()[:16]}"'
+
+def etag_matches(if_none_match: Optional[str], etag: str) -> bool:
+    """Check an If-None-Match header against an ETag using weak comparison"""
+    if not if_none_match:
+        return False
+    candidates = [tag.strip() for tag in if_none_match.split(",")]
+    return "*" in candidates or any(tag.removeprefix("W/") == etag.removeprefix("W/") for tag in candidates)
+
+# Largest per_page the list accepts. By default larger values are rejected with a
+# 400 so client bugs surface; PAGE_SIZE_MODE=clamp lowers them to the maximum instead.
+MAX_PAGE_SIZE = env_int("MAX_PAGE_SIZE", 100)
+CLAMP_PAGE_SIZE = (env_str("PAGE_SIZE_MODE") or "reject").lower() == "clamp"
 
-@router.get("/{item_id}", response_model=ItemResponse)
-def read_item(item_id: int, db: Session = Depends(get_db)):
-    """Get a specific item by ID"""
-    db_item = db.query(Item).filter(Item.id == item_id).first()
+def checked_page_size(per_page: int) -> int:
+    """Apply MAX_PAGE_SIZE to a requested page size according to PAGE_SIZE_MODE"""
+    if per_page <= MAX_PAGE_SIZE:
+        return per_page
+    if CLAMP_PAGE_SIZE:
+        return MAX_PAGE_SIZE
+    raise BadRequestError(f"per_page exceeds maximum of {MAX_PAGE_SIZE}")
+
+def paginate(query, page: int, per_page: int) -> Page[ItemResponse]:
+    """Run a query for one page of items and wrap it with paging metadata"""
+    total = query.count()
+    rows = query.offset((page - 1) * per_page).limit(per_page).all()
+    return Page[ItemResponse](
+        items=[ItemResponse.model_validate(row) for row in rows],
+        total=total,
+        page=page,
+        per_page=per_page,
+        has_next=page * per_page < total,
+    )
+
+@router.get("/", response_model=Page[ItemResponse], responses={
+    304: {"description": "Not modified since the ETag in If-None-Match"},
+    400: {"description": "per_page exceeds MAX_PAGE_SIZE (unless PAGE_SIZE_MODE=clamp)"},
+})
+def read_items(
+    request: Request,
+    response: Response,
+    page: int = Query(1, ge=1),
+    per_page: int = Query(100, ge=1, description="Items per page, at most MAX_PAGE_SIZE (default 100)"),
+    item_filter: ItemFilter = Depends(list_filter),
+    db: Session = Depends(get_read_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """Get one page of the items matching the filter, honouring If-None-Match for conditional requests"""
+    per_page = checked_page_size(per_page)
+    etag = items_list_etag(db, user_id, page, per_page, item_filter)
+    if etag_matches(request.headers.get("if-none-match"), etag):
+        return Response(status_code=status.HTTP_304_NOT_MODIFIED, headers={"ETag": etag})
+    response.headers["ETag"] = etag
+    # Keyed by the ETag (which covers page, per_page and filter) so a body is never
+    # served under a validator computed from different data
+    return items_cache.get_or_load(
+        (user_id, etag),
+        lambda: paginate(query_items(db, user_id, item_filter).order_by(*LIST_ORDER), page, per_page),
+    )
+
+TOTAL_COUNT_HEADER = "X-Total-Count"
+
+@router.head("/", responses={200: {"description": f"Number of live items in the {TOTAL_COUNT_HEADER} header"}})
+def count_items(
+    completed: Optional[bool] = Query(None, description="Only count completed (true) or open (false) items"),
+    db: Session = Depends(get_read_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """Report the number of items in a header without a body, for cheap polling"""
+    count = query_items(db, user_id, ItemFilter(completed=completed)).count()
+    return Response(headers={TOTAL_COUNT_HEADER: str(count)})
+
+def as_naive_utc(value: datetime) -> datetime:
+    """Database timestamps are stored as naive UTC; normalise client-supplied ones to match"""
+    if value.tzinfo is None:
+        return value
+    return value.astimezone(timezone.utc).replace(tzinfo=None)
+
+@router.get("/changes", response_model=List[ItemResponse])
+def read_items_changed_since(
+    since: datetime,
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    Get items created or updated after an ISO-8601 timestamp, oldest change first.
+    Trashed items are included with `deleted_at` set so clients can drop them
+    from a local cache.
+    """
+    since = as_naive_utc(since)
+    return (
+        scoped_items(db, user_id, deleted=None)
+        .filter(or_(Item.created_at > since, Item.updated_at > since))
+        .order_by(Item.updated_at, Item.id)
+        .all()
+    )
+
+@router.get("/diff", response_model=ItemDiff, responses={400: {"description": "`from` is later than `to`"}})
+def read_items_diff(
+    from_: datetime = Query(..., alias="from"),
+    to: datetime = Query(...),
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    What changed between two ISO-8601 timestamps, as the window after `from` up
+    to and including `to`. Items created in the window are `added`, older items
+    changed in it are `updated`, and older items moved to the trash in it are
+    `deleted`; an item both created and trashed in the window is left out. Items
+    are returned as they are now, since earlier versions aren't stored.
+    """
+    start, end = as_naive_utc(from_), as_naive_utc(to)
+    if start > end:
+        raise BadRequestError("from must not be later than to")
+
+    def in_window(column):
+        return (column > start) & (column <= end)
+
+    # Trashing also bumps updated_at, so rows trashed in the window count only as deleted
+    not_deleted = or_(Item.deleted_at.is_(None), Item.deleted_at > end)
+    items = scoped_items(db, user_id, deleted=None)
+    order = (Item.updated_at, Item.id)
+    return ItemDiff(
+        added=items.filter(in_window(Item.created_at), not_deleted).order_by(*order).all(),
+        updated=items.filter(Item.created_at <= start, in_window(Item.updated_at), not_deleted).order_by(*order).all(),
+        deleted=items.filter(Item.created_at <= start, in_window(Item.deleted_at)).order_by(*order).all(),
+    )
+
+@router.get("/deleted", response_model=List[ItemResponse])
+def read_deleted_items(db: Session = Depends(get_db), user_id: Optional[str] = Depends(get_current_user_id)):
+    """Get the soft-deleted items in the trash, most recently deleted first"""
+    return scoped_items(db, user_id, deleted=True).order_by(Item.deleted_at.desc()).all()
+
+MAX_IDS_PER_REQUEST = 200
+
+@router.get(
+    "/by-ids",
+    response_model=List[ItemResponse],
+    responses={400: {"description": f"More than {MAX_IDS_PER_REQUEST} ids were requested"}},
+)
+def read_items_by_ids(
+    ids: List[int] = Query(..., description="Repeat for each id, e.g. ?ids=1&ids=2"),
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    Get several items in one call, in the order their ids were given. Ids that
+    don't exist (or are trashed) are left out; repeated ids are returned once.
+    """
+    if len(ids) > MAX_IDS_PER_REQUEST:
+        raise BadRequestError(f"At most {MAX_IDS_PER_REQUEST} ids can be requested at once")
+    rows = {item.id: item for item in scoped_items(db, user_id).filter(Item.id.in_(ids))}
+    return [rows[item_id] for item_id in dict.fromkeys(ids) if item_id in rows]
+
+@router.post(
+    "/delete-ids",
+    response_model=DeleteIdsResponse,
+    responses={
+        400: {"description": f"More than {MAX_IDS_PER_REQUEST} ids were given"},
+        **UNAUTHORIZED_RESPONSE,
+    },
+)
+def delete_items_by_ids(request: DeleteIdsRequest, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Move several items to the trash in one transaction. Ids that are already
+    trashed or don't exist (for example deleted meanwhile by another client) are
+    not an error: they are reported in `missing` while the rest are deleted.
+    """
+    if len(request.ids) > MAX_IDS_PER_REQUEST:
+        raise BadRequestError(f"At most {MAX_IDS_PER_REQUEST} ids can be deleted at once")
+    ids = list(dict.fromkeys(request.ids))
+    rows = scoped_items(db, user_id).filter(Item.id.in_(ids)).all()
+    for db_item in rows:
+        db_item.deleted_at = func.now()
+        record_event(db, "deleted", db_item)
+    after_commit(db, items_cache.invalidate)
+    deleted = {db_item.id for db_item in rows}
+    return DeleteIdsResponse(
+        deleted=[item_id for item_id in ids if item_id in deleted],
+        missing=[item_id for item_id in ids if item_id not in deleted],
+    )
+
+# Largest `limit` for the recent-items endpoint; larger values are capped to it
+MAX_RECENT_ITEMS = 20
+
+@router.get("/recent", response_model=List[ItemResponse])
+def read_recent_items(
+    limit: int = Query(5, ge=1, description=f"Number of items to return, capped at {MAX_RECENT_ITEMS}"),
+    db: Session = Depends(get_read_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    The most recently created or bumped items, newest first, for small embeds such as a
+    "recent items" widget. A single ordered query with a LIMIT and no paging
+    metadata; results are shared through the list cache when it is enabled.
+    """
+    limit = min(limit, MAX_RECENT_ITEMS)
+    return items_cache.get_or_load(
+        ("recent", user_id, limit),
+        lambda: [
+            ItemResponse.model_validate(row)
+            for row in scoped_items(db, user_id).order_by(*NEWEST_FIRST).limit(limit)
+        ],
+    )
+
+@router.get("/{item_id}", response_model=ItemResponse, responses={304: {"description": "Content unchanged since the ETag in If-None-Match"}})
+def read_item(
+    item_id: int,
+    request: Request,
+    response: Response,
+    db: Session = Depends(get_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """Get a specific item by ID, with a weak ETag derived from its content"""
+    db_item = scoped_items(db, user_id).filter(Item.id == item_id).first()
     if db_item is None:
-        raise HTTPException(status_code=404, detail="Item not found")
+        raise NotFoundError("Item not found")
+    item = ItemResponse.model_validate(db_item)
+    etag = f'W/"{item.content_hash()}"'
+    if etag_matches(request.headers.get("if-none-match"), etag):
+        return Response(status_code=status.HTTP_304_NOT_MODIFIED, headers={"ETag": etag})
+    response.headers["ETag"] = etag
+    return item
+
+@router.put(
+    "/{item_id}",
+    response_model=ItemResponse,
+    responses={409: {"description": "Version mismatch; the item was changed concurrently"}, **UNAUTHORIZED_RESPONSE},
+)
+def update_item(item_id: int, item: ItemUpdate, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """Update an existing item if its version still matches the one the client last read"""
+    changes = item.dict(exclude_unset=True, exclude={"version"})
+    return apply_item_changes(db, user_id, item_id, changes, item.version)
+
+@router.patch(
+    "/{item_id}",
+    response_model=ItemResponse,
+    responses={
+        400: {"description": "The patch does not change any field"},
+        409: {"description": "Version mismatch; the item was changed concurrently"},
+        **UNAUTHORIZED_RESPONSE,
+    },
+)
+def patch_item(item_id: int, patch: ItemPatch, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """Change only the fields present in the request body, optionally checking the version"""
+    changes = patch.model_dump(exclude_unset=True, exclude={"version"})
+    if not changes:
+        raise BadRequestError("Patch must change at least one field")
+    return apply_item_changes(db, user_id, item_id, changes, patch.version)
+
+def apply_item_changes(db: Session, user_id: Optional[str], item_id: int, changes: dict, expected_version: Optional[int]) -> Item:
+    """
+    Write `changes` to an item and bump its version in a single UPDATE. When
+    `expected_version` is given the update only applies if nobody else has
+    changed the row since the client read it (409 otherwise).
+    """
+    if "name" in changes:
+        # Bulk updates skip the model's validators; the schema has already cleaned
+        # the name, so this only guards callers that build `changes` themselves
+        changes = {**changes, "name": clean_item_name(changes["name"])}
+    query = scoped_items(db, user_id).filter(Item.id == item_id)
+    if expected_version is not None:
+        query = query.filter(Item.version == expected_version)
+    with name_length_errors(db):
+        updated = query.update({**changes, "version": Item.version + 1}, synchronize_session=False)
+    if updated == 0:
+        db.rollback()
+        if scoped_items(db, user_id).filter(Item.id == item_id).first() is None:
+            raise NotFoundError("Item not found")
+        raise ConflictError("Item was modified by another request; reload and try again")
+
+    db_item = scoped_items(db, user_id).filter(Item.id == item_id).first()
+    record_event(db, "updated", db_item)
+    after_commit(db, items_cache.invalidate)
     return db_item
 
-@router.put("/{item_id}", response_model=ItemResponse)
-def update_item(item_id: int, item: ItemUpdate, db: Session = Depends(get_db)):
-    """Update an existing item"""
-    db_item = db.query(Item).filter(Item.id == item_id).first()
+@router.post("/{item_id}/bump", response_model=ItemResponse, responses=UNAUTHORIZED_RESPONSE)
+def bump_item(item_id: int, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """
+    Move an item to the top of newest-first lists (such as /recent) without
+    editing it, by setting `bumped_at` to now. `created_at` keeps the real
+    creation time. Returns the updated item.
+    """
+    return apply_item_changes(db, user_id, item_id, {"bumped_at": func.now()}, None)
+
+@router.delete("/{item_id}", status_code=status.HTTP_204_NO_CONTENT, responses=UNAUTHORIZED_RESPONSE)
+def delete_item(item_id: int, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """Move an item to the trash; it can be brought back with the restore endpoint"""
+    db_item = scoped_items(db, user_id).filter(Item.id == item_id).first()
     if db_item is None:
-        raise HTTPException(status_code=404, detail="Item not found")
+        raise NotFoundError("Item not found")
     
-    update_data = item.dict(exclude_unset=True)
-    for key, value in update_data.items():
-        setattr(db_item, key, value)
-    
-    db.commit()
-    db.refresh(db_item)
-    return db_item
+    db_item.deleted_at = func.now()
+    record_event(db, "deleted", db_item)
+    after_commit(db, items_cache.invalidate)
+    return None
 
-@router.delete("/{item_id}", status_code=status.HTTP_204_NO_CONTENT)
-def delete_item(item_id: int, db: Session = Depends(get_db)):
-    """Delete an item from the database"""
-    db_item = db.query(Item).filter(Item.id == item_id).first()
+@router.post("/{item_id}/restore", response_model=ItemResponse, responses=UNAUTHORIZED_RESPONSE)
+def restore_item(item_id: int, db: Session = Depends(get_transaction), user_id: Optional[str] = Depends(get_current_user_id)):
+    """Restore a soft-deleted item from the trash"""
+    db_item = scoped_items(db, user_id, deleted=True).filter(Item.id == item_id).first()
     if db_item is None:
-        raise HTTPException(status_code=404, detail="Item not found")
-    
-    db.delete(db_item)
-    db.commit()
-    return None 
\ No newline at end of file
+        raise NotFoundError("Item not found in trash")
+
+    hold_until_done(db, quota_lock)
+    check_quota(db)
+    db_item.deleted_at = None
+    record_event(db, "restored", db_item)
+    after_commit(db, items_cache.invalidate)
+    return db_item 
\ No newline at end of file
@@ -0,0 +1,41 @@
+import hmac
+from argon2 import PasswordHasher
+from argon2.exceptions import InvalidHashError, VerificationError
+from fastapi import APIRouter, Request, Response, status
+
+from repo_src.backend.config import env_str
+from repo_src.backend.data.schemas import LoginRequest
+from repo_src.backend.functions.errors import UnauthorizedError
+from repo_src.backend.middleware.admin_session import ADMIN_SESSION_KEY
+
+router = APIRouter(prefix="/api", tags=["auth"])
+
+password_hasher = PasswordHasher()
+
+def admin_login_enabled() -> bool:
+    """Login is only offered when both ADMIN_USER and ADMIN_PASSWORD_HASH are set"""
+    return bool(env_str("ADMIN_USER") and env_str("ADMIN_PASSWORD_HASH"))
+
+def verify_admin(username: str, password: str) -> bool:
+    """Check credentials against ADMIN_USER and the argon2 hash in ADMIN_PASSWORD_HASH"""
+    username_ok = hmac.compare_digest(username.encode(), (env_str("ADMIN_USER") or "").encode())
+    try:
+        # Always verify, even for a wrong username, so timing doesn't reveal which part failed
+        password_ok = password_hasher.verify(env_str("ADMIN_PASSWORD_HASH") or "", password)
+    except (VerificationError, InvalidHashError):
+        password_ok = False
+    return username_ok and password_ok
+
+@router.post("/login", status_code=status.HTTP_204_NO_CONTENT, responses={401: {"description": "Wrong username or password"}})
+def login(credentials: LoginRequest, request: Request):
+    """Start an admin session, allowing the mutating item endpoints"""
+    if not verify_admin(credentials.username, credentials.password):
+        raise UnauthorizedError("Wrong username or password")
+    request.session[ADMIN_SESSION_KEY] = True
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
+
+@router.post("/logout", status_code=status.HTTP_204_NO_CONTENT)
+def logout(request: Request):
+    """End the admin session"""
+    request.session.clear()
+    return Response(status_code=status.HTTP_204_NO_CONTENT)
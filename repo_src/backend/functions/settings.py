@@ -0,0 +1,66 @@
+import json
+from fastapi import APIRouter, Depends
+from pydantic import ValidationError
+from sqlalchemy.orm import Session
+from typing import Any, Optional
+
+from repo_src.backend.database.connection import get_db
+from repo_src.backend.database.models import Setting
+from repo_src.backend.data.schemas import SettingValue, Settings
+from repo_src.backend.functions.auth import get_current_user_id
+from repo_src.backend.functions.errors import BadRequestError, NotFoundError
+from repo_src.backend.functions.validation import combined_message, field_errors
+
+router = APIRouter(prefix="/api/settings", tags=["settings"])
+
+def settings_owner(user_id: Optional[str]) -> str:
+    """Settings rows are keyed by user; single-user mode stores them under an empty id"""
+    return user_id or ""
+
+def check_key(key: str) -> None:
+    if key not in Settings.model_fields:
+        raise NotFoundError(f"Unknown setting: {key}")
+
+def get_settings(db: Session, user_id: Optional[str]) -> Settings:
+    """Every setting, with stored values over the defaults"""
+    rows = db.query(Setting).filter(Setting.user_id == settings_owner(user_id)).all()
+    stored = {row.key: json.loads(row.value) for row in rows if row.key in Settings.model_fields}
+    return Settings(**stored)
+
+def set_setting(db: Session, user_id: Optional[str], key: str, value: Any) -> Any:
+    """
+    Validate `value` against the key's type and store it. Returns the value as
+    stored (coerced to the field's type). Raises BadRequestError when it doesn't fit.
+    """
+    check_key(key)
+    try:
+        value = getattr(Settings.model_validate({key: value}), key)
+    except ValidationError as e:
+        raise BadRequestError(combined_message(field_errors(e.errors())))
+    row = db.get(Setting, (settings_owner(user_id), key))
+    if row is None:
+        row = Setting(user_id=settings_owner(user_id), key=key)
+        db.add(row)
+    row.value = json.dumps(value)
+    db.commit()
+    return value
+
+@router.get("/", response_model=Settings)
+def read_settings(db: Session = Depends(get_db), user_id: Optional[str] = Depends(get_current_user_id)):
+    """All UI preferences, for the frontend to load at startup; unset ones have their defaults"""
+    return get_settings(db, user_id)
+
+@router.get("/{key}", response_model=SettingValue, responses={404: {"description": "Not an allowed setting"}})
+def read_setting(key: str, db: Session = Depends(get_db), user_id: Optional[str] = Depends(get_current_user_id)):
+    """One preference, or its default when it was never set"""
+    check_key(key)
+    return SettingValue(value=getattr(get_settings(db, user_id), key))
+
+@router.put(
+    "/{key}",
+    response_model=SettingValue,
+    responses={400: {"description": "The value doesn't fit the setting's type"}, 404: {"description": "Not an allowed setting"}},
+)
+def write_setting(key: str, body: SettingValue, db: Session = Depends(get_db), user_id: Optional[str] = Depends(get_current_user_id)):
+    """Store one preference, e.g. `PUT /api/settings/page_size` with `{"value": 50}`"""
+    return SettingValue(value=set_setting(db, user_id, key, body.value))
@@ -0,0 +1,48 @@
+from datetime import date, datetime, time, timedelta, timezone
+from fastapi import APIRouter, Depends, Query
+from sqlalchemy import func
+from sqlalchemy.orm import Session
+from typing import Dict, List, Optional
+
+from repo_src.backend.database.connection import get_analytics_db
+from repo_src.backend.database.models import Item
+from repo_src.backend.data.schemas import DayCount
+from repo_src.backend.functions.auth import get_current_user_id
+from repo_src.backend.functions.items import scoped_items
+
+router = APIRouter(prefix="/api/items/stats", tags=["stats"])
+
+DEFAULT_STATS_DAYS = 30
+MAX_STATS_DAYS = 366
+
+def fill_missing_days(counts: Dict[str, int], first_day: date, days: int) -> List[DayCount]:
+    """One entry per day from `first_day`, using 0 for days with no items"""
+    result = []
+    for offset in range(days):
+        day = (first_day + timedelta(days=offset)).isoformat()
+        result.append(DayCount(date=day, count=counts.get(day, 0)))
+    return result
+
+@router.get("/per-day", response_model=List[DayCount])
+def read_items_per_day(
+    days: int = Query(DEFAULT_STATS_DAYS, ge=1, le=MAX_STATS_DAYS),
+    db: Session = Depends(get_analytics_db),
+    user_id: Optional[str] = Depends(get_current_user_id),
+):
+    """
+    Number of items created on each of the last `days` days (UTC), oldest first
+    and including today. Days without any items are reported with a count of 0.
+    """
+    first_day = datetime.now(timezone.utc).date() - timedelta(days=days - 1)
+    created_day = func.date(Item.created_at)
+    rows = (
+        scoped_items(db, user_id)
+        .with_entities(created_day, func.count())
+        # Database timestamps are naive UTC
+        .filter(Item.created_at >= datetime.combine(first_day, time.min))
+        .group_by(created_day)
+        .all()
+    )
+    # SQLite returns the day as a string, other databases as a date
+    counts = {str(day): count for day, count in rows}
+    return fill_missing_days(counts, first_day, days)
@@ -0,0 +1,55 @@
+from fastapi import Request, status
+from fastapi.exceptions import RequestValidationError
+from fastapi.responses import JSONResponse
+from typing import Any, Dict, List, Sequence
+
+from repo_src.backend.data.schemas import FieldError, ValidationErrorResponse
+
+# Request locations FastAPI prefixes onto error locs; clients only care about the field path
+REQUEST_LOCATIONS = {"body", "query", "path", "header", "cookie"}
+
+# Error types reported when the whole body is valid JSON but not an object
+NOT_AN_OBJECT_TYPES = {"model_attributes_type", "dict_type"}
+
+def is_malformed_body(errors: Sequence[Dict[str, Any]]) -> bool:
+    """
+    True when the request body is not valid JSON or not a JSON object, as opposed
+    to a well-formed payload with bad field values. Such requests get a 400.
+    """
+    for err in errors:
+        if err.get("type") == "json_invalid":
+            return True
+        if err.get("type") in NOT_AN_OBJECT_TYPES and tuple(err.get("loc", ())) == ("body",):
+            return True
+    return False
+
+def field_errors(errors: Sequence[Dict[str, Any]]) -> List[FieldError]:
+    """Convert pydantic/FastAPI error dicts into field/message pairs"""
+    pairs = []
+    for err in errors:
+        if err.get("type") == "json_invalid":
+            # The loc is the character offset of the syntax error, not a field
+            position = list(err.get("loc", ()))[-1]
+            reason = err.get("ctx", {}).get("error", "JSON decode error")
+            pairs.append(FieldError(field="body", message=f"Malformed JSON at position {position}: {reason}"))
+            continue
+        loc = list(err.get("loc", ()))
+        if loc and loc[0] in REQUEST_LOCATIONS:
+            loc = loc[1:]
+        pairs.append(FieldError(field=".".join(map(str, loc)) or "body", message=err.get("msg", "Invalid value")))
+    return pairs
+
+def combined_message(errors: List[FieldError]) -> str:
+    """Render field errors as a single human-readable string for simple consumers"""
+    return "; ".join(f"{e.field}: {e.message}" for e in errors)
+
+async def validation_exception_handler(request: Request, exc: RequestValidationError) -> JSONResponse:
+    """
+    Return request validation failures as structured field errors: 400 for a body
+    that isn't a JSON object, 422 for invalid field values
+    """
+    raw_errors = exc.errors()
+    errors = field_errors(raw_errors)
+    body = ValidationErrorResponse(detail=combined_message(errors), errors=errors)
+    status_code = status.HTTP_400_BAD_REQUEST if is_malformed_body(raw_errors) else status.HTTP_422_UNPROCESSABLE_ENTITY
+    return JSONResponse(status_code=status_code, content=body.model_dump())
@@ -0,0 +1,54 @@
+import json
+import logging
+from datetime import datetime, timezone
+
+from repo_src.backend.config import env_str
+
+# Log output format, chosen with LOG_FORMAT: "pretty" (default) for readable
+# lines during development, or "json" for one JSON object per line in
+# production. The level comes from LOG_LEVEL in both cases.
+LOG_FORMATS = {"pretty", "json"}
+PRETTY_FORMAT = "%(asctime)s %(levelname)-8s %(name)s: %(message)s"
+
+class JsonFormatter(logging.Formatter):
+    """Formats each record as a single-line JSON object"""
+
+    def format(self, record: logging.LogRecord) -> str:
+        entry = {
+            "timestamp": datetime.fromtimestamp(record.created, timezone.utc).isoformat(),
+            "level": record.levelname,
+            "logger": record.name,
+            "message": record.getMessage(),
+        }
+        if record.exc_info:
+            entry["exception"] = self.formatException(record.exc_info)
+        return json.dumps(entry)
+
+def log_format() -> str:
+    value = (env_str("LOG_FORMAT") or "pretty").lower()
+    if value not in LOG_FORMATS:
+        print(f"Ignoring unknown LOG_FORMAT {value!r}, using 'pretty'")
+        return "pretty"
+    return value
+
+def logging_config() -> dict:
+    """
+    A `logging.config.dictConfig` configuration for the root logger and uvicorn's
+    loggers, so server and access logs share the selected format.
+    """
+    level = (env_str("LOG_LEVEL") or "INFO").upper()
+    if log_format() == "json":
+        formatter = {"()": JsonFormatter}
+    else:
+        formatter = {"format": PRETTY_FORMAT}
+    return {
+        "version": 1,
+        "disable_existing_loggers": False,
+        "formatters": {"default": formatter},
+        "handlers": {"default": {"class": "logging.StreamHandler", "formatter": "default"}},
+        "root": {"handlers": ["default"], "level": level},
+        "loggers": {
+            name: {"handlers": ["default"], "level": level, "propagate": False}
+            for name in ("uvicorn", "uvicorn.error", "uvicorn.access")
+        },
+    }
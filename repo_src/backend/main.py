@@ -1,8 +1,16 @@
-from fastapi import FastAPI
+from fastapi import FastAPI, status
+from fastapi.responses import HTMLResponse, JSONResponse, PlainTextResponse, Response
+from fastapi.exceptions import RequestValidationError
 from fastapi.middleware.cors import CORSMiddleware
+from starlette.middleware.sessions import SessionMiddleware
 from dotenv import load_dotenv
+import asyncio
 import os
+import secrets
+import sys
+import anyio.to_thread
 from contextlib import asynccontextmanager
+from typing import List
 
 # Load environment variables from .env file if it exists
 # This is particularly useful for local development.
@@ -31,21 +39,212 @@ else:
 # as db connection might depend on them.
 from repo_src.backend.database.setup import init_db
 from repo_src.backend.database import models, connection # For example endpoints
-from repo_src.backend.functions.items import router as items_router # Import the items router
+from repo_src.backend.database.health import AppStatus, keepalive_loop, pool_health, run_startup
+from repo_src.backend.database.purge import purge_loop
+from repo_src.backend.functions.items import TOTAL_COUNT_HEADER, router as items_router # Import the items router
+from repo_src.backend.functions.feed import router as feed_router
+from repo_src.backend.functions.export import router as export_router
+from repo_src.backend.functions.stats import router as stats_router
+from repo_src.backend.functions.events import router as events_router
+from repo_src.backend.functions.settings import router as settings_router
+from repo_src.backend.functions.session_auth import admin_login_enabled, router as session_auth_router
+from repo_src.backend.functions.validation import validation_exception_handler
+from repo_src.backend.functions.errors import ApiError, OperationalError, PoolTimeoutError, api_error_handler, database_unavailable_handler, pool_timeout_handler
+from repo_src.backend.middleware.admin_session import AdminSessionMiddleware
+from repo_src.backend.middleware.api_key import ApiKeyMiddleware
+from repo_src.backend.middleware.body_limit import BodyLimitMiddleware
+from repo_src.backend.middleware.request_id import REQUEST_ID_HEADER, RequestIdMiddleware
+from repo_src.backend.middleware.robots import ALLOW_ALL_ROBOTS_TXT, NOINDEX_ROBOTS_TXT, RobotsTagMiddleware
+from repo_src.backend.middleware.security_headers import DEFAULT_CSP, SecurityHeadersMiddleware
+from repo_src.backend.middleware.startup_gate import StartupGateMiddleware
+from repo_src.backend.config import base_path, dangerous_operations_enabled, env_bool, env_int, env_str
+from repo_src.backend.logging_config import log_format, logging_config
+from repo_src.backend.swagger import swagger_init_js, swagger_ui_html
+from repo_src.backend.build_info import BUILD_TIME, GIT_COMMIT
+from repo_src.backend.data.markdown_render import markdown_enabled
+from repo_src.backend.data.schemas import VersionInfo
+from repo_src.backend.spa import DEFAULT_ASSET_CACHE_CONTROL, DEFAULT_HTML_CACHE_CONTROL, SpaStaticFiles
+import logging.config
+
+# Apply LOG_FORMAT/LOG_LEVEL to the root and uvicorn loggers. This runs after uvicorn
+# has set up its own logging, so it also applies when started with the uvicorn CLI.
+logging.config.dictConfig(logging_config())
+
+# Listen address chosen when started with `python -m repo_src.backend.main`; with the
+# uvicorn CLI the address comes from its own options and isn't known here.
+bind_address = None
+
+def enabled_features() -> List[str]:
+    """Names of the optional features turned on by configuration"""
+    features = {
+        "api_key": bool(api_key),
+        "admin_login": admin_login_enabled(),
+        "security_headers": env_bool("SECURITY_HEADERS_ENABLED", default=True),
+        "csp_script_nonce": env_bool("SECURITY_HEADERS_ENABLED", default=True) and env_bool("CSP_SCRIPT_NONCE"),
+        "openapi": openapi_enabled,
+        "noindex": noindex,
+        "markdown": markdown_enabled(),
+        "dangerous_operations": dangerous_operations_enabled(),
+        "static_site": bool(site_root),
+    }
+    return [name for name, on in features.items() if on]
+
+def log_startup_banner(app: FastAPI) -> None:
+    """Print the effective configuration once at startup, with database credentials redacted"""
+    read_url = connection.DATABASE_READ_URL
+    analytics_url = connection.ANALYTICS_DATABASE_URL
+    settings = {
+        "version": app.version,
+        "commit": GIT_COMMIT or "unknown",
+        "bind address": bind_address or "set by the uvicorn command line",
+        "base path": BASE_PATH or "/",
+        "database": connection.redact_db_url(connection.DATABASE_URL),
+        "read replica": connection.redact_db_url(read_url) if read_url else "none",
+        "analytics database": connection.redact_db_url(analytics_url) if analytics_url else "main database",
+        "pool acquire timeout (ms)": env_int("DATABASE_ACQUIRE_TIMEOUT_MS", 5000),
+        "pool idle timeout (s)": env_int("DATABASE_IDLE_TIMEOUT_SECS", 600) or "off",
+        "pool max lifetime (s)": env_int("DATABASE_MAX_LIFETIME_SECS", 1800) or "off",
+        "worker threads": env_int("WORKER_THREADS", 0) or "default",
+        "max body bytes": env_int("MAX_BODY_BYTES", 1024 * 1024),
+        "items cache TTL (ms)": env_int("ITEMS_CACHE_TTL_MS", 0),
+        "log format": log_format(),
+        "static site": site_root or "off",
+    }
+    print("Effective configuration:")
+    for name, value in settings.items():
+        print(f"  {name}: {value}")
+    print(f"  features enabled: {', '.join(enabled_features()) or 'none'}")
+
+async def start_up():
+    """
+    Initialize the database and create tables, in the background so the server is
+    up and /ready reports `starting` while migrations run. Any failure (such as
+    a schema mismatch with DATABASE_SCHEMA_STRICT) stops the process, as it did
+    when initialization ran before the server started listening.
+    """
+    try:
+        await run_startup(init_db)
+    except Exception as e:
+        print(f"Application startup failed, stopping: {e!r}")
+        # SystemExit isn't swallowed by the task: it leaves the event loop and ends the server
+        sys.exit(1)
 
 @asynccontextmanager
 async def lifespan(app: FastAPI):
-    # Startup: Initialize database
+    log_startup_banner(app)
+    # Sync endpoints (all the database routes) run on a shared thread pool; WORKER_THREADS
+    # sets its size for constrained containers. Unset keeps the default of 40.
+    worker_threads = env_int("WORKER_THREADS", 0)
+    if worker_threads > 0:
+        anyio.to_thread.current_default_thread_limiter().total_tokens = worker_threads
+        print(f"Using {worker_threads} worker threads")
+    # Startup: Initialize database in the background; API requests get 503 until it's done
     print("Application startup: Initializing database...")
-    init_db() # Initialize database and create tables
-    print("Application startup complete.")
+    startup = asyncio.create_task(start_up())
+    # Periodically ping the pool so stale connections show up in /ready between
+    # probes. DATABASE_KEEPALIVE_SECONDS=0 turns this off.
+    keepalive_seconds = env_int("DATABASE_KEEPALIVE_SECONDS", 30)
+    keepalive = asyncio.create_task(keepalive_loop(keepalive_seconds)) if keepalive_seconds > 0 else None
+    # Opt-in: every PURGE_INTERVAL_HOURS, permanently delete items trashed more than
+    # PURGE_RETENTION_DAYS ago. Unset (0) keeps trashed items until restored.
+    purge_interval_hours = env_int("PURGE_INTERVAL_HOURS", 0)
+    async def purge_once_started():
+        # Raises, and so never purges, if startup failed
+        await startup
+        await purge_loop(purge_interval_hours, env_int("PURGE_RETENTION_DAYS", 30))
+    purge = asyncio.create_task(purge_once_started()) if purge_interval_hours > 0 else None
+    print("Application startup complete; database initialization continues in the background.")
     yield
     # Shutdown: Clean up resources if needed
     print("Application shutdown: Cleaning up resources...")
-    # Any cleanup code would go here
+    for task in (startup, keepalive, purge):
+        if task is not None:
+            task.cancel()
     print("Application shutdown complete.")
 
-app = FastAPI(title="AI-Friendly Repository Backend", version="1.0.0", lifespan=lifespan)
+# Optional sub-path for hosting behind a reverse proxy (e.g. SITE_BASE_PATH=/app).
+# All routes are registered under it; the default empty path leaves them unchanged.
+BASE_PATH = base_path()
+
+# The OpenAPI spec and Swagger UI can be turned off with OPENAPI_ENABLED=false
+openapi_enabled = env_bool("OPENAPI_ENABLED", default=True)
+
+app = FastAPI(
+    title="AI-Friendly Repository Backend",
+    version="1.0.0",
+    lifespan=lifespan,
+    openapi_url=f"{BASE_PATH}/api-docs/openapi.json" if openapi_enabled else None,
+    docs_url=None,  # Served below without inline scripts, so the CSP can forbid them
+    redoc_url=f"{BASE_PATH}/redoc" if openapi_enabled else None,
+)
+
+# Until database setup and migrations have finished, API requests other than the
+# health check get a 503 with Retry-After instead of reaching a half-migrated database.
+app.add_middleware(
+    StartupGateMiddleware,
+    starting=lambda: pool_health.status == AppStatus.STARTING,
+    prefix=f"{BASE_PATH}/api",
+    exempt=(f"{BASE_PATH}/api/health",),
+)
+
+# Cap request bodies under /api (413 when exceeded) so a huge upload can't exhaust memory.
+# Static files and docs are unaffected.
+app.add_middleware(
+    BodyLimitMiddleware,
+    max_bytes=env_int("MAX_BODY_BYTES", 1024 * 1024),
+    prefix=f"{BASE_PATH}/api",
+)
+
+# Protect mutating API endpoints with a shared secret when API_KEY is set.
+# Added before CORS so that 401 responses still carry CORS headers.
+api_key = env_str("API_KEY")
+if api_key:
+    app.add_middleware(
+        ApiKeyMiddleware,
+        api_key=api_key,
+        protect_reads=env_bool("API_KEY_PROTECT_READS"),
+        prefix=f"{BASE_PATH}/api",
+    )
+else:
+    print("API_KEY is not set; API key authentication is disabled.")
+
+# Optional single-admin login: with ADMIN_USER and ADMIN_PASSWORD_HASH set, mutating
+# API requests need a session started via POST /api/login. The session middleware is
+# added after (outside) the check so that request.session is available to it.
+if admin_login_enabled():
+    app.add_middleware(
+        AdminSessionMiddleware,
+        protect_reads=env_bool("ADMIN_PROTECT_READS"),
+        prefix=f"{BASE_PATH}/api",
+        exempt=(f"{BASE_PATH}/api/login", f"{BASE_PATH}/api/logout"),
+    )
+    session_secret = env_str("SESSION_SECRET")
+    if not session_secret:
+        print("SESSION_SECRET is not set; using a random key, so logins won't survive a restart.")
+        session_secret = secrets.token_hex(32)
+    app.add_middleware(
+        SessionMiddleware,
+        secret_key=session_secret,
+        same_site="lax",
+        https_only=env_bool("SESSION_COOKIE_SECURE"),
+    )
+
+# Tag every request with an X-Request-Id for correlating errors with logs
+app.add_middleware(RequestIdMiddleware)
+
+# Baseline security headers; CONTENT_SECURITY_POLICY overrides (or, if blank, keeps) the default CSP.
+# CSP_SCRIPT_NONCE fills the nonce="__CSP_NONCE__" placeholder on pages' scripts with a per-response nonce.
+if env_bool("SECURITY_HEADERS_ENABLED", default=True):
+    app.add_middleware(
+        SecurityHeadersMiddleware,
+        content_security_policy=env_str("CONTENT_SECURITY_POLICY", DEFAULT_CSP),
+        script_nonce=env_bool("CSP_SCRIPT_NONCE"),
+    )
+
+# Keep staging/internal deployments out of search engines with NOINDEX=true
+noindex = env_bool("NOINDEX")
+if noindex:
+    app.add_middleware(RobotsTagMiddleware)
 
 # Configure CORS middleware
 app.add_middleware(
@@ -54,25 +253,126 @@ app.add_middleware(
     allow_credentials=True,
     allow_methods=["*"],  # Allow all methods
     allow_headers=["*"],  # Allow all headers
+    expose_headers=[REQUEST_ID_HEADER, TOTAL_COUNT_HEADER, "Retry-After"],  # Let the frontend read these response headers
 )
 
+# Return validation failures as field/message pairs
+app.add_exception_handler(RequestValidationError, validation_exception_handler)
+# Connection pool exhaustion is reported as 503 "server busy"
+app.add_exception_handler(PoolTimeoutError, pool_timeout_handler)
+# So is a database that can't be reached, so the frontend can show an outage banner
+app.add_exception_handler(OperationalError, database_unavailable_handler)
+# Errors raised by the REST handlers are returned as {"error": {"code", "message"}}
+app.add_exception_handler(ApiError, api_error_handler)
+
 # Include the items router
-app.include_router(items_router)
+app.include_router(items_router, prefix=BASE_PATH)
+app.include_router(feed_router, prefix=BASE_PATH)
+app.include_router(export_router, prefix=BASE_PATH)
+app.include_router(stats_router, prefix=BASE_PATH)
+app.include_router(events_router, prefix=BASE_PATH)
+app.include_router(settings_router, prefix=BASE_PATH)
+if admin_login_enabled():
+    app.include_router(session_auth_router, prefix=BASE_PATH)
+
+# Directory of the built frontend (e.g. repo_src/frontend/dist) to serve from the
+# backend. When unset, the frontend is served separately (e.g. by Vite).
+site_root = env_str("SITE_ROOT")
 
-@app.get("/")
-async def read_root():
-    """A simple root endpoint to confirm the API is running."""
-    return {"message": "Welcome to the Backend API. Database is initialized."}
+if not site_root:
+    @app.get(f"{BASE_PATH}/")
+    async def read_root():
+        """A simple root endpoint to confirm the API is running."""
+        return {"message": "Welcome to the Backend API. Database is initialized."}
 
-@app.get("/api/hello")
+@app.get(f"{BASE_PATH}/robots.txt", response_class=PlainTextResponse, include_in_schema=False)
+async def read_robots_txt():
+    """Disallow all crawling when NOINDEX is set, allow everything otherwise"""
+    return NOINDEX_ROBOTS_TXT if noindex else ALLOW_ALL_ROBOTS_TXT
+
+if openapi_enabled:
+    @app.get(f"{BASE_PATH}/swagger", response_class=HTMLResponse, include_in_schema=False)
+    async def read_swagger_ui():
+        """Swagger UI for the OpenAPI spec"""
+        return swagger_ui_html(f"{app.title} - Swagger UI", f"{BASE_PATH}/swagger/init.js")
+
+    @app.get(f"{BASE_PATH}/swagger/init.js", include_in_schema=False)
+    async def read_swagger_init():
+        """The Swagger UI bootstrap, kept out of the page so script-src needs no 'unsafe-inline'"""
+        return Response(swagger_init_js(app.openapi_url), media_type="text/javascript")
+
+@app.get(f"{BASE_PATH}/api/version", response_model=VersionInfo)
+async def read_version():
+    """The deployed version, git commit and build time, and the enabled optional features"""
+    return VersionInfo(version=app.version, commit=GIT_COMMIT, build_time=BUILD_TIME, features=enabled_features())
+
+@app.get(f"{BASE_PATH}/api/hello")
 async def read_hello():
     """A simple API endpoint to test connectivity."""
     return {"message": "Hello from FastAPI Backend!"}
 
+@app.get(f"{BASE_PATH}/ready")
+@app.get(f"{BASE_PATH}/api/health")  # Same check under /api, reachable through the frontend's dev proxy
+async def read_ready():
+    """
+    Readiness probe. Reports `starting` until startup (including migrations) has
+    finished, then `ready` or `degraded` from the latest database keepalive
+    check, checking now if the keepalive task is disabled. Only `ready` answers 200.
+    """
+    if pool_health.status != AppStatus.STARTING and env_int("DATABASE_KEEPALIVE_SECONDS", 30) <= 0:
+        await asyncio.to_thread(pool_health.check)
+    body = {
+        "status": pool_health.status.value,
+        "last_checked_at": pool_health.last_checked_at,
+        "database_failures": pool_health.failures,
+    }
+    ready = pool_health.status == AppStatus.READY
+    status_code = status.HTTP_200_OK if ready else status.HTTP_503_SERVICE_UNAVAILABLE
+    return JSONResponse(status_code=status_code, content=body)
+
+# Mounted last so every API route takes precedence over static files
+if site_root:
+    app.mount(
+        BASE_PATH or "/",
+        SpaStaticFiles(
+            directory=site_root,
+            html=True,
+            spa_fallback=env_bool("SPA_FALLBACK", default=True),
+            asset_cache_control=env_str("STATIC_ASSET_CACHE_CONTROL", DEFAULT_ASSET_CACHE_CONTROL),
+            html_cache_control=env_str("STATIC_HTML_CACHE_CONTROL", DEFAULT_HTML_CACHE_CONTROL),
+        ),
+        name="site",
+    )
+
 # Example (commented out) CRUD endpoints would go here
 # You would typically put these in separate router files (e.g., in an `api` or `routers` directory)
 # and include them in the main app.
 
+def resolve_bind_address():
+    """
+    Returns the (host, port) to listen on. A `SITE_ADDR` of the form `host:port`
+    (or `[ipv6]:port`) takes precedence over the `HOST`/`PORT` variables.
+    """
+    site_addr = env_str("SITE_ADDR")
+    if site_addr:
+        host, _, port = site_addr.rpartition(":")
+        host = host.strip("[]")
+        if host and port.isdigit() and 0 < int(port) < 65536:
+            print(f"Using listen address {host}:{port} from SITE_ADDR")
+            return host, int(port)
+        print(f"Ignoring unparseable SITE_ADDR {site_addr!r}; expected host:port")
+    host, port = env_str("HOST", "0.0.0.0"), int(os.getenv("PORT", "8000"))
+    print(f"Using listen address {host}:{port} from HOST/PORT")
+    return host, port
+
 if __name__ == "__main__":
     import uvicorn
-    uvicorn.run(app, host="0.0.0.0", port=int(os.getenv("PORT", "8000")), log_level=os.getenv("LOG_LEVEL", "info").lower())
+    host, port = resolve_bind_address()
+    bind_address = f"{host}:{port}"
+    uvicorn.run(
+        app,
+        host=host,
+        port=port,
+        log_level=os.getenv("LOG_LEVEL", "info").lower(),
+        log_config=None,  # Keep the configuration applied at import
+    )
@@ -0,0 +1,34 @@
+from starlette.middleware.base import BaseHTTPMiddleware
+from starlette.requests import Request
+from starlette.responses import JSONResponse
+
+from repo_src.backend.middleware.api_key import READ_METHODS
+
+# Session key set by the login endpoint once the admin credentials are verified
+ADMIN_SESSION_KEY = "admin"
+
+class AdminSessionMiddleware(BaseHTTPMiddleware):
+    """
+    Requires a logged-in admin session on requests under `prefix` (`/api`).
+
+    Mutating methods are always checked; reads only when `protect_reads` is set.
+    Paths in `exempt` (login and logout) and CORS preflights are never checked.
+    Must run inside Starlette's SessionMiddleware, which provides `request.session`.
+    """
+
+    def __init__(self, app, protect_reads: bool = False, prefix: str = "/api", exempt: tuple = ()):
+        super().__init__(app)
+        self.protect_reads = protect_reads
+        self.prefix = prefix
+        self.exempt = set(exempt)
+
+    async def dispatch(self, request: Request, call_next):
+        path = request.url.path
+        if not path.startswith(self.prefix) or path in self.exempt or request.method == "OPTIONS":
+            return await call_next(request)
+        if request.method in READ_METHODS and not self.protect_reads:
+            return await call_next(request)
+
+        if not request.session.get(ADMIN_SESSION_KEY):
+            return JSONResponse(status_code=401, content={"detail": "Login required"})
+        return await call_next(request)
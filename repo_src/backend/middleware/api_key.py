@@ -0,0 +1,31 @@
+import hmac
+from starlette.middleware.base import BaseHTTPMiddleware
+from starlette.requests import Request
+from starlette.responses import JSONResponse
+
+READ_METHODS = {"GET", "HEAD", "OPTIONS"}
+
+class ApiKeyMiddleware(BaseHTTPMiddleware):
+    """
+    Requires a matching `X-API-Key` header on requests under `prefix` (`/api`).
+
+    Mutating methods (POST/PUT/PATCH/DELETE) are always checked. Reads are only
+    checked when `protect_reads` is set. CORS preflight requests are never checked.
+    """
+
+    def __init__(self, app, api_key: str, protect_reads: bool = False, prefix: str = "/api"):
+        super().__init__(app)
+        self.api_key = api_key.encode()
+        self.protect_reads = protect_reads
+        self.prefix = prefix
+
+    async def dispatch(self, request: Request, call_next):
+        if not request.url.path.startswith(self.prefix) or request.method == "OPTIONS":
+            return await call_next(request)
+        if request.method in READ_METHODS and not self.protect_reads:
+            return await call_next(request)
+
+        provided = request.headers.get("x-api-key", "").encode()
+        if not hmac.compare_digest(provided, self.api_key):
+            return JSONResponse(status_code=401, content={"detail": "Invalid or missing API key"})
+        return await call_next(request)
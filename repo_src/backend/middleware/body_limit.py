@@ -0,0 +1,62 @@
+from starlette.responses import JSONResponse
+from starlette.types import ASGIApp, Message, Receive, Scope, Send
+
+class BodyLimitMiddleware:
+    """
+    Rejects requests under `prefix` (`/api`) whose body is larger than `max_bytes`
+    with a 413, before the endpoint runs.
+
+    A declared Content-Length is checked up front. Otherwise the body is read
+    into memory up to the limit and then replayed to the app, so chunked uploads
+    are bounded too. Written as plain ASGI middleware because it has to wrap the
+    request body stream.
+    """
+
+    def __init__(self, app: ASGIApp, max_bytes: int, prefix: str = "/api"):
+        self.app = app
+        self.max_bytes = max_bytes
+        self.prefix = prefix
+
+    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
+        if scope["type"] != "http" or not scope["path"].startswith(self.prefix):
+            await self.app(scope, receive, send)
+            return
+
+        headers = dict(scope["headers"])
+        content_length = headers.get(b"content-length", b"")
+        if content_length.isdigit() and int(content_length) > self.max_bytes:
+            await self.reject(scope, receive, send)
+            return
+
+        body = b""
+        more_body = True
+        while more_body:
+            message = await receive()
+            if message["type"] == "http.disconnect":
+                return
+            body += message.get("body", b"")
+            if len(body) > self.max_bytes:
+                await self.reject(scope, receive, send)
+                return
+            more_body = message.get("more_body", False)
+
+        replayed = False
+
+        async def replay() -> Message:
+            nonlocal replayed
+            if replayed:
+                return await receive()
+            replayed = True
+            return {"type": "http.request", "body": body, "more_body": False}
+
+        await self.app(scope, replay, send)
+
+    async def reject(self, scope: Scope, receive: Receive, send: Send) -> None:
+        response = JSONResponse(
+            status_code=413,
+            content={"error": {
+                "code": "payload_too_large",
+                "message": f"Request body exceeds the {self.max_bytes} byte limit",
+            }},
+        )
+        await response(scope, receive, send)
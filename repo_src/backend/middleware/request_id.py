@@ -0,0 +1,41 @@
+import re
+import uuid
+from contextvars import ContextVar
+from starlette.middleware.base import BaseHTTPMiddleware
+from starlette.requests import Request
+from starlette.responses import JSONResponse
+
+REQUEST_ID_HEADER = "X-Request-Id"
+# Incoming ids are only reused if they look like an opaque token, to keep logs clean
+VALID_REQUEST_ID = re.compile(r"^[A-Za-z0-9._-]{1,128}$")
+
+request_id_var: ContextVar[str] = ContextVar("request_id", default="-")
+
+def get_request_id() -> str:
+    """The id of the request currently being handled, or "-" outside a request"""
+    return request_id_var.get()
+
+class RequestIdMiddleware(BaseHTTPMiddleware):
+    """
+    Assigns every request an id, reusing a well-formed `X-Request-Id` header from
+    the client or proxy, and echoes it on the response. Unhandled errors are
+    logged with the id and returned as a 500 that includes it, so users can quote
+    it and operators can find the matching log line.
+    """
+
+    async def dispatch(self, request: Request, call_next):
+        incoming = request.headers.get(REQUEST_ID_HEADER, "")
+        request_id = incoming if VALID_REQUEST_ID.match(incoming) else uuid.uuid4().hex
+        request_id_var.set(request_id)
+        request.state.request_id = request_id
+
+        try:
+            response = await call_next(request)
+        except Exception as e:
+            print(f"[{request_id}] Unhandled error on {request.method} {request.url.path}: {e!r}")
+            response = JSONResponse(
+                status_code=500,
+                content={"detail": "Internal server error", "request_id": request_id},
+            )
+        response.headers[REQUEST_ID_HEADER] = request_id
+        return response
@@ -0,0 +1,17 @@
+from starlette.middleware.base import BaseHTTPMiddleware
+from starlette.requests import Request
+
+NOINDEX_ROBOTS_TAG = "noindex, nofollow"
+NOINDEX_ROBOTS_TXT = "User-agent: *\nDisallow: /\n"
+ALLOW_ALL_ROBOTS_TXT = "User-agent: *\nDisallow:\n"
+
+class RobotsTagMiddleware(BaseHTTPMiddleware):
+    """
+    Adds `X-Robots-Tag: noindex, nofollow` to every response so search engines
+    don't index a staging or internal deployment. Only installed when NOINDEX is set.
+    """
+
+    async def dispatch(self, request: Request, call_next):
+        response = await call_next(request)
+        response.headers["X-Robots-Tag"] = NOINDEX_ROBOTS_TAG
+        return response
@@ -0,0 +1,32 @@
+from typing import Callable, Iterable
+
+from starlette.middleware.base import BaseHTTPMiddleware
+from starlette.requests import Request
+from starlette.responses import JSONResponse
+
+STARTING_MESSAGE = "The server is still starting up; try again shortly"
+STARTING_RETRY_AFTER = 2
+
+class StartupGateMiddleware(BaseHTTPMiddleware):
+    """
+    Answers requests under `prefix` (`/api`) with a 503 while `starting()` is
+    true, so no endpoint touches the database before setup and migrations have
+    finished. The `server_busy` code and Retry-After header make clients retry,
+    as for an exhausted pool. Paths in `exempt` (the health check) always pass.
+    """
+
+    def __init__(self, app, starting: Callable[[], bool], prefix: str = "/api", exempt: Iterable[str] = ()):
+        super().__init__(app)
+        self.starting = starting
+        self.prefix = prefix
+        self.exempt = set(exempt)
+
+    async def dispatch(self, request: Request, call_next):
+        path = request.url.path
+        if path.startswith(self.prefix) and path not in self.exempt and self.starting():
+            return JSONResponse(
+                status_code=503,
+                content={"detail": STARTING_MESSAGE, "code": "server_busy"},
+                headers={"Retry-After": str(STARTING_RETRY_AFTER)},
+            )
+        return await call_next(request)
@@ -8,4 +8,9 @@ nox.options.reuse_existing_virtualenvs = True
 def tests(session):
     session.install("-r", "requirements.txt")  # Install runtime dependencies
     session.install("pytest", "pytest-cov", "pytest-asyncio", "httpx") # Install test dependencies
-    session.run("pytest", "-q", "--cov=.", "--cov-report=xml", "--cov-report=term-missing") # Run tests from backend dir 
\ No newline at end of file
+    session.run("pytest", "-q", "--cov=.", "--cov-report=xml", "--cov-report=term-missing") # Run tests from backend dir
+
+@nox.session(python=["3.11"])
+def bench(session):
+    session.install("-r", "requirements.txt")
+    session.run("python", "-m", "repo_src.backend.benchmarks.bench_crud", env={"PYTHONPATH": "../.."})
@@ -0,0 +1,53 @@
+from pathlib import PurePosixPath
+from starlette.exceptions import HTTPException
+from starlette.staticfiles import StaticFiles
+
+def is_client_route(path: str) -> bool:
+    """
+    True for paths the frontend router may handle, such as `items/3`. Paths that
+    look like files (`assets/app.js`, `pkg/app.wasm`) or are under `api/` are not,
+    so a genuinely missing asset or endpoint still gets a 404.
+    """
+    if path.split("/", 1)[0] == "api":
+        return False
+    return "." not in PurePosixPath(path).name
+
+# Vite writes bundles with a content hash in the file name under assets/, so
+# they can be cached forever; index.html must be revalidated to pick up new ones.
+FINGERPRINTED_DIR = "assets"
+DEFAULT_ASSET_CACHE_CONTROL = "public, max-age=31536000, immutable"
+DEFAULT_HTML_CACHE_CONTROL = "no-cache"
+
+class SpaStaticFiles(StaticFiles):
+    """
+    Serves the built frontend. With `spa_fallback`, unknown client routes get
+    `index.html` instead of a 404, so deep links into the app work on reload.
+    Fingerprinted assets get `asset_cache_control` and HTML pages get
+    `html_cache_control`; other files are sent without a Cache-Control header.
+    """
+
+    def __init__(
+        self,
+        *args,
+        spa_fallback: bool = True,
+        asset_cache_control: str = DEFAULT_ASSET_CACHE_CONTROL,
+        html_cache_control: str = DEFAULT_HTML_CACHE_CONTROL,
+        **kwargs,
+    ):
+        super().__init__(*args, **kwargs)
+        self.spa_fallback = spa_fallback
+        self.asset_cache_control = asset_cache_control
+        self.html_cache_control = html_cache_control
+
+    async def get_response(self, path: str, scope):
+        try:
+            response = await super().get_response(path, scope)
+        except HTTPException as exc:
+            if exc.status_code != 404 or not self.spa_fallback or not is_client_route(path):
+                raise
+            response = await super().get_response("index.html", scope)
+        if response.media_type == "text/html":
+            response.headers["Cache-Control"] = self.html_cache_control
+        elif path.split("/", 1)[0] == FINGERPRINTED_DIR:
+            response.headers["Cache-Control"] = self.asset_cache_control
+        return response
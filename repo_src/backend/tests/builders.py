@@ -0,0 +1,44 @@
+from datetime import datetime, timedelta
+from typing import Optional
+
+from sqlalchemy.orm import Session
+
+from repo_src.backend.database.models import Item
+
+class ItemBuilder:
+    """
+    Builds Item rows for tests. Only the name is required; chain the other
+    setters for the fields a test cares about, e.g.
+    `ItemBuilder("Buy milk").kind("task").completed().build()`.
+    """
+
+    def __init__(self, name: str = "Item"):
+        self.fields = {"name": name}
+
+    def description(self, description: Optional[str]) -> "ItemBuilder":
+        self.fields["description"] = description
+        return self
+
+    def kind(self, kind: str) -> "ItemBuilder":
+        self.fields["kind"] = kind
+        return self
+
+    def completed(self, completed: bool = True) -> "ItemBuilder":
+        self.fields["completed"] = completed
+        return self
+
+    def trashed(self, ago: timedelta = timedelta(0)) -> "ItemBuilder":
+        """Put the item in the trash, moved there `ago` before now"""
+        self.fields["deleted_at"] = datetime.utcnow() - ago
+        return self
+
+    def build(self) -> Item:
+        return Item(**self.fields)
+
+    def create(self, db: Session) -> Item:
+        """Insert the item, commit, and return it with its generated fields loaded"""
+        item = self.build()
+        db.add(item)
+        db.commit()
+        db.refresh(item)
+        return item
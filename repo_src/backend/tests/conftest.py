@@ -0,0 +1,5 @@
+import os
+
+# Point the app's engine at TEST_DATABASE_URL (in-memory by default) before any
+# backend module is imported, so tests never touch the development database.
+os.environ.setdefault("TESTING", "1")
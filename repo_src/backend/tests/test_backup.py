@@ -0,0 +1,39 @@
+import sys
+import os
+import pytest
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from repo_src.backend.database.backup import BackupError, parse_export
+
+ITEM = {
+    "id": 1,
+    "name": "Test",
+    "description": None,
+    "user_id": None,
+    "version": 1,
+    "position": None,
+    "created_at": "2024-01-01T00:00:00",
+    "updated_at": "2024-01-01T00:00:00",
+    "deleted_at": None,
+}
+
+def test_parse_export_reads_envelope():
+    items = parse_export({"schema_version": 1, "items": [ITEM]}, "test")
+    assert [item.id for item in items] == [1]
+
+def test_parse_export_accepts_legacy_array():
+    items = parse_export([ITEM], "test")
+    assert [item.name for item in items] == ["Test"]
+
+def test_parse_export_rejects_newer_schema_version():
+    with pytest.raises(BackupError, match="schema version 2"):
+        parse_export({"schema_version": 2, "items": []}, "test")
+
+def test_parse_export_rejects_names_the_api_would_reject():
+    with pytest.raises(BackupError, match="id 1"):
+        parse_export([{**ITEM, "name": "x" * 101}], "test")
+    with pytest.raises(BackupError, match="Name is required"):
+        parse_export([{**ITEM, "name": "   "}], "test")
+
+def test_parse_export_cleans_names_like_the_api():
+    items = parse_export([{**ITEM, "name": "Te\u202est"}], "test")
+    assert items[0].name == "Test"
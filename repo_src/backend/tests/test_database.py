@@ -1,4 +1,6 @@
+import json
 import pytest
+from datetime import datetime, timedelta
 from sqlalchemy import create_engine
 from sqlalchemy.orm import sessionmaker, Session as SQLAlchemySession # Renamed to avoid conflict
 from sqlalchemy.pool import StaticPool
@@ -8,54 +10,78 @@ from typing import Generator
 import sys
 import os
 sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
-from repo_src.backend.database.connection import Base, get_db
+from repo_src.backend.database.connection import Base, TransactionRoute, get_analytics_db, get_db, get_read_db, get_transaction, transaction_scope
 from repo_src.backend.database.models import Item # Import your models
-from repo_src.backend.main import app 
+from repo_src.backend.main import app
+from repo_src.backend.data.schemas import ItemFilter
+from repo_src.backend.functions import export as export_module
+from repo_src.backend.functions import items as items_module
+from repo_src.backend.functions.cache import TtlCache
+from repo_src.backend.functions.items import query_items
+from repo_src.backend.database.health import pool_health
+from repo_src.backend.database.purge import purge_trashed_items
+from repo_src.backend.tests.builders import ItemBuilder
 
+from fastapi import APIRouter, Depends, FastAPI
+from fastapi.responses import JSONResponse
 from fastapi.testclient import TestClient
 
-# Use an in-memory SQLite database for testing
+# In-memory SQLite for testing. Every connection to `:memory:` opens its own empty
+# database, so StaticPool hands the same single connection to every session: the
+# fixture's session and each TestClient request (run on other threads) all see
+# the same tables and rows.
 DATABASE_URL_TEST = "sqlite:///:memory:"
 
 engine_test = create_engine(
     DATABASE_URL_TEST,
     connect_args={"check_same_thread": False}, # Specific to SQLite
-    poolclass=StaticPool, # Use StaticPool for in-memory SQLite with TestClient for a single connection per test
+    poolclass=StaticPool,
 )
 TestingSessionLocal = sessionmaker(autocommit=False, autoflush=False, bind=engine_test)
 
+# Tables live for the whole module; db_session_func resets them after each test
+Base.metadata.create_all(bind=engine_test)
+
 @pytest.fixture(scope="function")
 def db_session_func() -> Generator[SQLAlchemySession, None, None]:
     """
     Pytest fixture to create a new database session for each test function.
-    It creates all tables before the test and drops them afterwards.
+    Afterwards the tables are dropped and recreated, so the next test starts empty.
     """
-    Base.metadata.create_all(bind=engine_test) # Create tables
     db = TestingSessionLocal()
     try:
         yield db
     finally:
         db.close()
-        Base.metadata.drop_all(bind=engine_test) # Drop tables after test
+        Base.metadata.drop_all(bind=engine_test)
+        Base.metadata.create_all(bind=engine_test)
 
 # Override the get_db dependency for testing FastAPI endpoints
 def override_get_db_for_tests():
     """
-    Overrides the get_db dependency in FastAPI to use the test database.
-    Ensures tables are created before yielding the session and dropped after.
-    This is crucial for TestClient tests that interact with the database.
+    Overrides the get_db dependency in FastAPI to use the test database. Tables
+    are not created or dropped here: a test making several requests (create, then
+    read back) must see its earlier writes. Use db_session_func to reset afterwards.
     """
-    Base.metadata.create_all(bind=engine_test) # Ensure tables are created for this test session scope
     db = TestingSessionLocal()
     try:
         yield db
     finally:
         db.close()
-        Base.metadata.drop_all(bind=engine_test) # Clean up: drop tables
+
+def override_get_transaction_for_tests():
+    """Overrides get_transaction the same way, keeping its commit-or-rollback behaviour"""
+    yield from transaction_scope(TestingSessionLocal)
 
 app.dependency_overrides[get_db] = override_get_db_for_tests
+app.dependency_overrides[get_transaction] = override_get_transaction_for_tests
+app.dependency_overrides[get_read_db] = override_get_db_for_tests
+app.dependency_overrides[get_analytics_db] = override_get_db_for_tests
 
 client = TestClient(app) # TestClient that uses the overridden get_db
+# Without a `with` block the lifespan doesn't run, so startup never finishes on its
+# own; mark it done so the API isn't held back with 503s
+pool_health.mark_started()
 
 def test_create_item_in_db(db_session_func: SQLAlchemySession):
     # Direct database interaction test using the db_session_func fixture
@@ -71,7 +97,187 @@ def test_create_item_in_db(db_session_func: SQLAlchemySession):
     assert retrieved_item is not None
     assert retrieved_item.name == "Test Item Direct"
 
+def test_model_applies_the_api_name_rules():
+    # Writes that bypass the request schemas (batch, restore, scripts) are validated too
+    with pytest.raises(ValueError, match="at most"):
+        Item(name="x" * 101)
+    with pytest.raises(ValueError, match="required"):
+        Item(name="  ")
+    assert Item(name="a\u202eb").name == "ab"
+
+def test_batch_create_reports_invalid_elements(db_session_func: SQLAlchemySession):
+    response = client.post("/api/items/batch", json=[{"name": "Fine"}, {"name": "x" * 101}, {"name": ""}])
+    assert response.status_code == 207
+    results = response.json()["results"]
+    assert results[0]["id"] is not None
+    assert [result["error"] is not None for result in results] == [False, True, True]
+
+def test_batch_failing_partway_persists_nothing(db_session_func: SQLAlchemySession, monkeypatch):
+    record_event = items_module.record_event
+    calls = []
+
+    def fail_on_second_event(db, kind, db_item):
+        calls.append(kind)
+        if len(calls) == 2:
+            raise RuntimeError("event store unavailable")
+        record_event(db, kind, db_item)
+
+    monkeypatch.setattr(items_module, "record_event", fail_on_second_event)
+    # RequestIdMiddleware turns the unhandled error into a 500 carrying the request id
+    response = client.post("/api/items/batch", json=[{"name": "First"}, {"name": "Second"}])
+    assert response.status_code == 500
+    assert response.json()["request_id"] == response.headers["x-request-id"]
+    assert db_session_func.query(Item).count() == 0
+
+    monkeypatch.setattr(items_module, "record_event", record_event)
+    assert client.post("/api/items/batch", json=[{"name": "First"}, {"name": "Second"}]).status_code == 201
+    assert db_session_func.query(Item).count() == 2
+
+def test_writes_persist_across_requests(db_session_func: SQLAlchemySession):
+    created = client.post("/api/items/", json={"name": "Persisted"})
+    assert created.status_code == 201
+    item_id = created.json()["id"]
+
+    fetched = client.get(f"/api/items/{item_id}")
+    assert fetched.status_code == 200
+    assert fetched.json()["name"] == "Persisted"
+    # The fixture's session shares the connection, so it sees the row too
+    assert db_session_func.get(Item, item_id).name == "Persisted"
+
+def test_list_etag_changes_with_every_write(db_session_func: SQLAlchemySession):
+    # All within the same second, so timestamps alone couldn't tell them apart
+    first_id = client.post("/api/items/", json={"name": "First"}).json()["id"]
+    etags = [client.get("/api/items/").headers["etag"]]
+    client.patch(f"/api/items/{first_id}", json={"name": "Renamed"})
+    etags.append(client.get("/api/items/").headers["etag"])
+    second_id = client.post("/api/items/", json={"name": "Second"}).json()["id"]
+    etags.append(client.get("/api/items/").headers["etag"])
+    client.post("/api/items/reorder", json={"order": [second_id, first_id]})
+    etags.append(client.get("/api/items/").headers["etag"])
+    assert len(set(etags)) == len(etags)
+
+def test_cached_list_follows_writes_from_other_workers(db_session_func: SQLAlchemySession, monkeypatch):
+    monkeypatch.setattr(items_module, "items_cache", TtlCache(60_000))
+    ItemBuilder("First").create(db_session_func)
+    before = client.get("/api/items/")
+    # Written without going through the API, so nothing invalidates this process's cache
+    ItemBuilder("Second").create(db_session_func)
+    after = client.get("/api/items/")
+    assert after.headers["etag"] != before.headers["etag"]
+    assert [item["name"] for item in after.json()["items"]] == ["First", "Second"]
+
+def test_export_streams_every_batch_on_its_own_session(db_session_func: SQLAlchemySession, monkeypatch):
+    # The stream doesn't use get_db, so point its session factory at the test database
+    monkeypatch.setattr(export_module, "SessionLocal", TestingSessionLocal)
+    monkeypatch.setattr(export_module, "EXPORT_BATCH_SIZE", 2)
+    for name in ["One", "Two", "Three"]:
+        ItemBuilder(name).create(db_session_func)
+    response = client.get("/api/export.jsonl")
+    assert response.status_code == 200
+    assert [json.loads(line)["name"] for line in response.text.splitlines()] == ["One", "Two", "Three"]
+
+def test_item_history_lists_changes_in_order(db_session_func: SQLAlchemySession):
+    item_id = client.post("/api/items/", json={"name": "Draft"}).json()["id"]
+    # Only created so far: nothing to show
+    assert client.get(f"/api/events/items/{item_id}").json() == []
+
+    client.patch(f"/api/items/{item_id}", json={"name": "Final"})
+    client.delete(f"/api/items/{item_id}")
+    history = client.get(f"/api/events/items/{item_id}").json()
+    assert [event["kind"] for event in history] == ["created", "updated", "deleted"]
+    assert history[1]["payload"]["name"] == "Final"
+
+    assert client.get("/api/events/items/999999").status_code == 404
+
+def test_settings_allow_only_known_keys_and_types(db_session_func: SQLAlchemySession):
+    assert client.get("/api/settings/").json() == {"page_size": 20, "theme": "system", "hide_completed": False}
+
+    assert client.put("/api/settings/page_size", json={"value": 50}).json() == {"value": 50}
+    assert client.get("/api/settings/page_size").json() == {"value": 50}
+    assert client.get("/api/settings/").json()["page_size"] == 50
+
+    assert client.put("/api/settings/theme", json={"value": "pink"}).status_code == 400
+    assert client.put("/api/settings/page_size", json={"value": 0}).status_code == 400
+    assert client.put("/api/settings/anything", json={"value": "x"}).status_code == 404
+
+def test_bump_moves_item_to_top_of_recent(db_session_func: SQLAlchemySession):
+    older = client.post("/api/items/", json={"name": "Older"}).json()
+    newer = client.post("/api/items/", json={"name": "Newer"}).json()
+    db_session_func.query(Item).filter(Item.id == older["id"]).update({Item.created_at: datetime(2020, 1, 1)})
+    db_session_func.query(Item).filter(Item.id == newer["id"]).update({Item.created_at: datetime(2020, 1, 2)})
+    db_session_func.commit()
+    assert [item["name"] for item in client.get("/api/items/recent").json()] == ["Newer", "Older"]
+
+    bumped = client.post(f"/api/items/{older['id']}/bump").json()
+    assert bumped["bumped_at"] is not None
+    assert bumped["created_at"].startswith("2020-01-01")
+    assert [item["name"] for item in client.get("/api/items/recent").json()] == ["Older", "Newer"]
+
+def test_transaction_scope_commits_or_rolls_back_as_a_whole(db_session_func: SQLAlchemySession):
+    failed = transaction_scope(TestingSessionLocal)
+    db = next(failed)
+    db.add_all([ItemBuilder("First").build(), ItemBuilder("Second").build()])
+    db.flush()
+    with pytest.raises(RuntimeError):
+        failed.throw(RuntimeError("handler failed"))
+    assert db_session_func.query(Item).count() == 0
+
+    succeeded = transaction_scope(TestingSessionLocal)
+    db = next(succeeded)
+    db.add_all([ItemBuilder("First").build(), ItemBuilder("Second").build()])
+    with pytest.raises(StopIteration):
+        next(succeeded)
+    assert db_session_func.query(Item).count() == 2
+
+def test_returned_error_response_rolls_back_the_transaction(db_session_func: SQLAlchemySession):
+    router = APIRouter(route_class=TransactionRoute)
+
+    @router.post("/refuse")
+    def add_then_refuse(db: SQLAlchemySession = Depends(get_transaction)):
+        db.add(ItemBuilder("Refused").build())
+        db.flush()
+        return JSONResponse(status_code=409, content={"detail": "Refused"})
+
+    @router.post("/accept")
+    def add(db: SQLAlchemySession = Depends(get_transaction)):
+        db.add(ItemBuilder("Accepted").build())
+        return {"ok": True}
+
+    local_app = FastAPI()
+    local_app.include_router(router)
+    local_app.dependency_overrides[get_transaction] = override_get_transaction_for_tests
+    local_client = TestClient(local_app)
+
+    assert local_client.post("/refuse").status_code == 409
+    assert local_client.post("/accept").status_code == 200
+    assert [item.name for item in db_session_func.query(Item)] == ["Accepted"]
+
+def test_purge_deletes_only_items_trashed_before_the_retention(db_session_func: SQLAlchemySession):
+    ItemBuilder("Live").create(db_session_func)
+    ItemBuilder("Recently trashed").trashed(timedelta(days=1)).create(db_session_func)
+    ItemBuilder("Long trashed").trashed(timedelta(days=40)).create(db_session_func)
+
+    assert purge_trashed_items(db_session_func, retention_days=30) == 1
+    assert sorted(item.name for item in db_session_func.query(Item)) == ["Live", "Recently trashed"]
+
 def test_read_root_endpoint():
     response = client.get("/") # Uses TestClient with overridden DB
     assert response.status_code == 200
-    assert response.json() == {"message": "Welcome to the Backend API. Database is initialized."} 
\ No newline at end of file
+    assert response.json() == {"message": "Welcome to the Backend API. Database is initialized."}
+
+
+def test_query_items_applies_each_filter_field(db_session_func: SQLAlchemySession):
+    ItemBuilder("Buy milk").kind("task").create(db_session_func)
+    ItemBuilder("Meeting notes").description("Discuss 100% of the milk budget").kind("note").create(db_session_func)
+    ItemBuilder("Plain item").completed().create(db_session_func)
+
+    def names(**fields):
+        return sorted(item.name for item in query_items(db_session_func, None, ItemFilter(**fields)))
+
+    assert names() == ["Buy milk", "Meeting notes", "Plain item"]
+    assert names(text="MILK") == ["Buy milk", "Meeting notes"]
+    assert names(text="milk", kind="task") == ["Buy milk"]
+    assert names(text="100%") == ["Meeting notes"]
+    assert names(text="%") == ["Meeting notes"]
+    assert names(completed=True) == ["Plain item"]
+    assert names(completed=False) == ["Buy milk", "Meeting notes"]
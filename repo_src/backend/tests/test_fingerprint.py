@@ -0,0 +1,14 @@
+import sys
+import os
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from repo_src.backend.data.fingerprint import content_hash
+
+def test_content_hash_is_pinned():
+    # Changing these values invalidates every stored ETag; only update them deliberately
+    assert content_hash("Buy milk", "2 litres", "task") == "061553ea3e8d58ad"
+    assert content_hash("Buy milk", None, "item") == "b15df9982597de38"
+
+def test_content_hash_distinguishes_fields():
+    assert content_hash("a", "b", "item") != content_hash("a b", None, "item")
+    assert content_hash("a", None, "item") != content_hash("a", "", "item")
+    assert content_hash("a", None, "item") != content_hash("a", None, "note")
@@ -0,0 +1,69 @@
+import asyncio
+import pytest
+import sys
+import os
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from fastapi.testclient import TestClient
+from repo_src.backend import main as main_module
+from repo_src.backend.database import health as health_module
+from repo_src.backend.database import setup as setup_module
+from repo_src.backend.database.health import AppStatus, PoolHealth
+
+# No `with` block, so the lifespan (and with it the real startup) never runs
+client = TestClient(main_module.app)
+
+def refuse_connection():
+    raise OSError("database down")
+
+def test_status_stays_starting_until_startup_completes():
+    health = PoolHealth()
+    assert health.check()
+    assert health.status == AppStatus.STARTING
+    health.mark_started()
+    assert health.status == AppStatus.READY
+
+def test_failed_check_degrades_and_recovery_restores_ready(monkeypatch):
+    health = PoolHealth()
+    health.check()
+    health.mark_started()
+
+    monkeypatch.setattr(health_module.engine, "connect", refuse_connection)
+    assert not health.check()
+    assert health.status == AppStatus.DEGRADED
+
+    monkeypatch.undo()
+    assert health.check()
+    assert health.status == AppStatus.READY
+
+def test_strict_schema_mismatch_stops_startup(monkeypatch):
+    health = PoolHealth()
+    monkeypatch.setattr(health_module, "pool_health", health)
+    monkeypatch.setenv("DATABASE_SCHEMA_STRICT", "true")
+    monkeypatch.setattr(setup_module, "verify_schema", lambda engine, table: ["missing column items.completed"])
+
+    with pytest.raises(SystemExit) as stopped:
+        asyncio.run(main_module.start_up())
+    assert stopped.value.code == 1
+    assert health.status == AppStatus.STARTING
+
+def test_ready_reports_each_status(monkeypatch):
+    health = PoolHealth()
+    monkeypatch.setattr(main_module, "pool_health", health)
+    # Check the pool on every request rather than relying on the keepalive task
+    monkeypatch.setenv("DATABASE_KEEPALIVE_SECONDS", "0")
+
+    response = client.get("/ready")
+    assert (response.status_code, response.json()["status"]) == (503, "starting")
+    # The API waits for migrations; the health check under /api doesn't
+    response = client.get("/api/items/")
+    assert (response.status_code, response.headers["retry-after"]) == (503, "2")
+    assert client.get("/api/health").json()["status"] == "starting"
+
+    health.mark_started()
+    response = client.get("/ready")
+    assert (response.status_code, response.json()["status"]) == (200, "ready")
+
+    monkeypatch.setattr(health_module.engine, "connect", refuse_connection)
+    response = client.get("/ready")
+    assert (response.status_code, response.json()["status"]) == (503, "degraded")
+    assert response.json()["database_failures"] == 1
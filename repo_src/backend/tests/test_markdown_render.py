@@ -0,0 +1,26 @@
+import sys
+import os
+import pytest
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from repo_src.backend.data.markdown_render import render_item_markdown
+
+pytest.importorskip("markdown")
+pytest.importorskip("nh3")
+
+def test_render_item_markdown_formats_text():
+    html = render_item_markdown("Some **bold** text and [a link](https://example.com)")
+    assert "<strong>bold</strong>" in html
+    assert 'href="https://example.com"' in html
+
+def test_render_item_markdown_neutralizes_scripts_and_links():
+    html = render_item_markdown(
+        "<script>alert(1)</script>\n\n"
+        "[click](javascript:alert(1))\n\n"
+        '<img src=x onerror="alert(1)">\n\n'
+        '<a href="https://example.com" onclick="alert(1)">ok</a>'
+    )
+    assert "<script" not in html
+    assert "javascript:" not in html
+    assert "<img" not in html
+    assert "onerror" not in html
+    assert "onclick" not in html
@@ -0,0 +1,16 @@
+import sys
+import os
+from sqlalchemy import create_engine, inspect
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from repo_src.backend.database.connection import Base
+from repo_src.backend.database.migrations import MIGRATIONS, check_migrations
+from repo_src.backend.database.models import Item
+
+def test_every_migration_applies_to_the_baseline_schema():
+    # An empty database has no items table, so the check replays every migration
+    source = create_engine("sqlite://")
+    applied, problems = check_migrations(source, Base.metadata, Item.__table__)
+    assert applied == [version for version, _ in MIGRATIONS]
+    assert problems == []
+    # The checked database itself is left untouched
+    assert inspect(source).get_table_names() == []
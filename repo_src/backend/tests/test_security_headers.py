@@ -0,0 +1,56 @@
+import re
+import sys
+import os
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from fastapi import FastAPI
+from fastapi.responses import HTMLResponse
+from fastapi.testclient import TestClient
+from repo_src.backend.middleware.security_headers import DEFAULT_CSP, SecurityHeadersMiddleware, with_script_nonce
+from repo_src.backend.swagger import SWAGGER_UI_DIST, swagger_init_js, swagger_ui_html
+
+def test_nonce_replaces_unsafe_inline_scripts():
+    policy = with_script_nonce(DEFAULT_CSP, "abc")
+    assert "script-src 'self' https://cdn.jsdelivr.net 'nonce-abc'" in policy
+    # Inline styles are not affected
+    assert "style-src 'self' 'unsafe-inline'" in policy
+
+def test_nonce_adds_script_src_when_missing():
+    assert with_script_nonce("default-src 'self'", "abc") == "default-src 'self'; script-src 'self' 'nonce-abc'"
+
+def test_only_scripts_with_the_placeholder_get_the_nonce():
+    page = FastAPI()
+
+    @page.get("/", response_class=HTMLResponse)
+    def read_page():
+        return '<script nonce="__CSP_NONCE__">boot()</script><script>injected()</script>'
+
+    page.add_middleware(SecurityHeadersMiddleware, script_nonce=True)
+    response = TestClient(page).get("/")
+    nonce = re.search(r"'nonce-([^']+)'", response.headers["content-security-policy"]).group(1)
+    assert response.text == f'<script nonce="{nonce}">boot()</script><script>injected()</script>'
+
+def test_nonced_pages_are_not_cached_or_revalidated():
+    page = FastAPI()
+
+    @page.get("/", response_class=HTMLResponse)
+    def read_page():
+        headers = {"ETag": '"abc"', "Last-Modified": "Mon, 01 Jan 2024 00:00:00 GMT", "Cache-Control": "no-cache"}
+        return HTMLResponse('<script nonce="__CSP_NONCE__"></script>', headers=headers)
+
+    page.add_middleware(SecurityHeadersMiddleware, script_nonce=True)
+    response = TestClient(page).get("/")
+    assert "etag" not in response.headers
+    assert "last-modified" not in response.headers
+    assert response.headers["cache-control"] == "no-store"
+
+def test_default_policy_forbids_inline_scripts():
+    script_src = next(d for d in DEFAULT_CSP.split(";") if d.split()[0] == "script-src")
+    assert "'unsafe-inline'" not in script_src
+
+def test_swagger_page_loads_its_bootstrap_from_a_file():
+    page = swagger_ui_html("Docs", "/swagger/init.js")
+    assert re.findall(r"<script[^>]*>", page) == [
+        f'<script src="{SWAGGER_UI_DIST}/swagger-ui-bundle.js">',
+        '<script src="/swagger/init.js">',
+    ]
+    assert '"url": "/api-docs/openapi.json"' in swagger_init_js("/api-docs/openapi.json")
@@ -0,0 +1,13 @@
+import sys
+import os
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from repo_src.backend.spa import is_client_route
+
+def test_client_routes_fall_back_to_index():
+    assert is_client_route("items/3")
+    assert is_client_route("settings")
+
+def test_assets_and_api_paths_keep_404():
+    assert not is_client_route("assets/index-abc123.js")
+    assert not is_client_route("pkg/app_bg.wasm")
+    assert not is_client_route("api/unknown")
@@ -0,0 +1,33 @@
+import sys
+import os
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from repo_src.backend.functions.validation import combined_message, field_errors, is_malformed_body
+
+def test_field_errors_strips_request_location():
+    errors = field_errors([
+        {"loc": ("body", "name"), "msg": "Field required"},
+        {"loc": ("query", "limit"), "msg": "Input should be a valid integer"},
+    ])
+    assert [(e.field, e.message) for e in errors] == [
+        ("name", "Field required"),
+        ("limit", "Input should be a valid integer"),
+    ]
+
+def test_combined_message_joins_field_errors():
+    errors = field_errors([{"loc": ("body",), "msg": "Input should be a valid dictionary"}])
+    assert combined_message(errors) == "body: Input should be a valid dictionary"
+
+def test_field_errors_describes_json_syntax_errors():
+    errors = field_errors([{
+        "type": "json_invalid", "loc": ("body", 12), "msg": "JSON decode error",
+        "ctx": {"error": "Expecting ',' delimiter"},
+    }])
+    assert [(e.field, e.message) for e in errors] == [
+        ("body", "Malformed JSON at position 12: Expecting ',' delimiter"),
+    ]
+
+def test_is_malformed_body_only_for_unreadable_bodies():
+    assert is_malformed_body([{"type": "json_invalid", "loc": ("body", 0)}])
+    assert is_malformed_body([{"type": "model_attributes_type", "loc": ("body",)}])
+    assert not is_malformed_body([{"type": "missing", "loc": ("body", "name")}])
+    assert not is_malformed_body([{"type": "dict_type", "loc": ("body", "payload")}])
@@ -0,0 +1,64 @@
+import sys
+import os
+import json
+import pytest
+sys.path.insert(0, os.path.abspath(os.path.join(os.path.dirname(__file__), '../../..')))
+from pydantic import ValidationError
+from repo_src.backend.data.schemas import ItemCreate, ItemPatch, ItemUpdate
+from repo_src.backend.data.validators import ITEM_NAME_MAX_LENGTH, NAME_MULTILINE_MESSAGE, clean_item_name, sanitize_item_text
+
+def test_sanitize_item_text_keeps_markup_as_text():
+    text = "<script>alert('x')</script> & <b>bold</b>"
+    assert sanitize_item_text(text) == text
+
+def test_sanitize_item_text_strips_control_and_bidi_characters():
+    assert sanitize_item_text("a\x00b\x1bc\u202ed") == "abcd"
+    assert sanitize_item_text("line one\nline\ttwo") == "line one\nline\ttwo"
+
+def test_clean_item_name_enforces_max_length():
+    assert clean_item_name("x" * ITEM_NAME_MAX_LENGTH) == "x" * ITEM_NAME_MAX_LENGTH
+    with pytest.raises(ValueError, match="at most"):
+        clean_item_name("x" * (ITEM_NAME_MAX_LENGTH + 1))
+
+def test_clean_item_name_counts_characters_not_bytes():
+    emoji = "\U0001f600" * ITEM_NAME_MAX_LENGTH
+    assert clean_item_name(emoji) == emoji
+    accented = "\u00e9" * ITEM_NAME_MAX_LENGTH
+    assert clean_item_name(accented) == accented
+    with pytest.raises(ValueError, match="at most"):
+        clean_item_name(emoji + "\U0001f600")
+
+def test_clean_item_name_composes_combining_accents():
+    # "e" + combining acute accent is one character after NFC normalization
+    decomposed = "e\u0301" * ITEM_NAME_MAX_LENGTH
+    assert clean_item_name(decomposed) == "\u00e9" * ITEM_NAME_MAX_LENGTH
+
+def test_clean_item_name_newlines_are_configurable(monkeypatch):
+    assert clean_item_name("first line\nsecond line") == "first line\nsecond line"
+    monkeypatch.setenv("ITEM_NAME_MULTILINE", "false")
+    with pytest.raises(ValueError, match=NAME_MULTILINE_MESSAGE):
+        clean_item_name("first line\nsecond line")
+
+@pytest.mark.parametrize("schema, extra", [(ItemCreate, {}), (ItemUpdate, {"version": 1}), (ItemPatch, {})])
+def test_request_schemas_share_the_name_rules(schema, extra):
+    assert schema(name="a\u202eb", **extra).name == "ab"
+    with pytest.raises(ValidationError, match="at most"):
+        schema(name="x" * (ITEM_NAME_MAX_LENGTH + 1), **extra)
+
+def load_name_cases():
+    """The accept/reject table shared with the frontend's src/utils/__tests__/itemName.test.ts"""
+    path = os.path.join(os.path.dirname(__file__), '../data/item_name_cases.json')
+    with open(path, encoding="utf-8") as f:
+        cases = json.load(f)
+    return [
+        pytest.param(case["name"] if "name" in case else case["repeat"] * case["count"], case["valid"], id=case["case"])
+        for case in cases
+    ]
+
+@pytest.mark.parametrize("name, valid", load_name_cases())
+def test_item_create_matches_client_name_rules(name, valid):
+    if valid:
+        ItemCreate(name=name)
+    else:
+        with pytest.raises(ValidationError):
+            ItemCreate(name=name)
@@ -1,76 +1,346 @@
-import { useState, useEffect } from 'react'
+import { useState, useEffect, useRef } from 'react'
 import './styles/App.css'
 import ItemForm from './components/ItemForm'
 import ItemList from './components/ItemList'
+import SkeletonList from './components/SkeletonList'
+import Pagination from './components/Pagination'
+import { Item, NewItem, Page } from './types/item'
+import { useT } from './i18n/LangContext'
+import { apiUrl, httpError, retryAfterMs } from './utils/http'
+import { optimistic } from './utils/optimistic'
+import { UndoStack } from './utils/undoStack'
+import { loadCachedPage, saveCachedPage } from './utils/offlineCache'
 
-// Define item type
-interface Item {
-  id: number
-  name: string
-  description: string | null
-  created_at: string
-  updated_at: string
+// Number of items shown per page of the list
+const PAGE_SIZE = 20
+
+// Retries after a busy 503 before the list is shown as unavailable instead
+const MAX_BUSY_RETRIES = 5
+
+// localStorage key remembering the "hide completed" toggle between visits
+const HIDE_COMPLETED_KEY = 'items.hideCompleted'
+
+// Storage can be blocked (private browsing); the toggle then lasts for the visit only
+function loadHideCompleted(): boolean {
+  try {
+    return localStorage.getItem(HIDE_COMPLETED_KEY) === 'true'
+  } catch {
+    return false
+  }
+}
+
+function saveHideCompleted(hide: boolean) {
+  try {
+    localStorage.setItem(HIDE_COMPLETED_KEY, String(hide))
+  } catch {
+    // Not persisted; the default applies on the next visit
+  }
 }
 
 function App() {
+  const t = useT()
   const [items, setItems] = useState<Item[]>([])
+  const [page, setPage] = useState(1)
+  const [totalPages, setTotalPages] = useState(1)
   const [loading, setLoading] = useState(true)
   const [error, setError] = useState<string | null>(null)
+  // Set while the backend reports its database as unreachable (HTTP 503)
+  const [unavailable, setUnavailable] = useState(false)
+  // Set while the backend is too busy to serve the list and a retry is scheduled
+  const [busy, setBusy] = useState(false)
+  // Set when the list shown is the saved offline copy because the request failed
+  const [offline, setOffline] = useState(false)
+  // Deletes and renames made in this session, for Ctrl+Z / Ctrl+Shift+Z
+  const [undoStack] = useState(() => new UndoStack())
+  const [hideCompleted, setHideCompleted] = useState(loadHideCompleted)
+  // Ids of the rows ticked for a bulk action; only rows on the current page
+  const [selected, setSelected] = useState<Set<number>>(() => new Set())
+  // Set when a bulk delete found some of the selected items already gone
+  const [notice, setNotice] = useState<string | null>(null)
+  // Extra query parameters for the list, also part of the offline cache key
+  const filter = hideCompleted ? 'completed=false' : ''
+  // The scheduled retry after a busy 503, and how many have run in a row
+  const retryTimer = useRef<number | undefined>(undefined)
+  const busyRetries = useRef(0)
+
+  // Show a page's data, keeping the page number in range of the total
+  const showPage = (data: Page<Item>) => {
+    const pages = Math.max(1, Math.ceil(data.total / data.per_page))
+    setItems(data.items)
+    setTotalPages(pages)
+    // Drop selected ids that are no longer shown, e.g. deleted one at a time
+    setSelected(current => new Set(data.items.map(item => item.id).filter(id => current.has(id))))
+    // Step back if deletions emptied the last page
+    if (page > pages) {
+      setPage(pages)
+    }
+  }
 
-  // Fetch items from the API
+  // Fetch the current page of items from the API. The saved offline copy of the
+  // page is shown meanwhile, and kept if the request fails.
   const fetchItems = async () => {
+    window.clearTimeout(retryTimer.current)
+    let answered = false
+    const cached = loadCachedPage(page, PAGE_SIZE, filter)
+    cached.then(saved => {
+      if (saved && !answered) {
+        showPage(saved)
+        setLoading(false)
+      }
+    })
     try {
       setLoading(true)
-      const response = await fetch('/api/items')
+      const query = `page=${page}&per_page=${PAGE_SIZE}${filter ? `&${filter}` : ''}`
+      const response = await fetch(apiUrl(`/api/items?${query}`))
+      if (response.status === 503) {
+        // A busy server says when to retry; without Retry-After, or once it has
+        // stayed busy for MAX_BUSY_RETRIES tries, it's an outage
+        const delay = retryAfterMs(response)
+        if (delay !== null && busyRetries.current < MAX_BUSY_RETRIES) {
+          busyRetries.current += 1
+          setBusy(true)
+          // Through the ref, so the retry uses the page and filter current by then
+          retryTimer.current = window.setTimeout(() => fetchItemsRef.current(), delay)
+        } else {
+          setBusy(false)
+          setUnavailable(true)
+        }
+        return
+      }
       if (!response.ok) {
-        throw new Error(`Error fetching items: ${response.status}`)
+        throw httpError('fetching items', response)
       }
-      const data = await response.json()
-      setItems(data)
+      const data: Page<Item> = await response.json()
+      answered = true
+      busyRetries.current = 0
+      showPage(data)
+      saveCachedPage(page, PAGE_SIZE, data, filter)
+      setUnavailable(false)
+      setBusy(false)
+      setOffline(false)
       setError(null)
     } catch (err) {
+      answered = true
       console.error('Error fetching items:', err)
-      setError(err instanceof Error ? err.message : 'Unknown error')
+      const saved = await cached
+      if (saved) {
+        showPage(saved)
+        setOffline(true)
+      } else {
+        setError(err instanceof Error ? err.message : 'Unknown error')
+      }
     } finally {
+      answered = true
       setLoading(false)
     }
   }
 
-  // Add a new item
-  const addItem = async (name: string, description: string) => {
+  // The latest fetchItems, for timers and undo entries that outlive the render creating them
+  const fetchItemsRef = useRef(fetchItems)
+  fetchItemsRef.current = fetchItems
+
+  // Add a new item, showing a placeholder for it until the server confirms. The
+  // form passes the same idempotency key again when it retries a failed submit.
+  const addItem = async (newItem: NewItem, idempotencyKey: string) => {
+    const now = new Date().toISOString()
+    const placeholder: Item = {
+      id: -Date.now(),
+      name: newItem.name,
+      description: newItem.description,
+      kind: newItem.kind ?? 'item',
+      markdown: newItem.markdown ?? false,
+      description_html: null,
+      completed: false,
+      user_id: null,
+      version: 1,
+      created_at: now,
+      updated_at: now,
+      bumped_at: null,
+      deleted_at: null,
+    }
     try {
-      const response = await fetch('/api/items/', {
-        method: 'POST',
-        headers: {
-          'Content-Type': 'application/json',
-        },
-        body: JSON.stringify({ name, description }),
+      await optimistic(setItems, current => [...current, placeholder], async () => {
+        const response = await fetch(apiUrl('/api/items/'), {
+          method: 'POST',
+          headers: {
+            'Content-Type': 'application/json',
+            // Lets the server recognise a retried submission of the same item
+            'Idempotency-Key': idempotencyKey,
+          },
+          body: JSON.stringify(newItem),
+        })
+
+        if (!response.ok) {
+          throw httpError('creating item', response)
+        }
       })
-      
-      if (!response.ok) {
-        throw new Error(`Error creating item: ${response.status}`)
-      }
-      
-      // Refresh the items list
+
+      // Refresh the items list to replace the placeholder
       fetchItems()
     } catch (err) {
       console.error('Error adding item:', err)
       setError(err instanceof Error ? err.message : 'Unknown error')
+      // Let the form know the item wasn't saved so it keeps the input
+      throw err
+    }
+  }
+
+  // Server calls replayed by undo/redo. They send no version, so they apply
+  // on top of whatever the item looks like by then.
+  const requestRename = async (id: number, name: string) => {
+    const response = await fetch(apiUrl(`/api/items/${id}`), {
+      method: 'PATCH',
+      headers: {
+        'Content-Type': 'application/json',
+      },
+      body: JSON.stringify({ name }),
+    })
+    if (!response.ok) {
+      throw httpError('editing item', response)
+    }
+  }
+
+  const requestDelete = async (id: number) => {
+    const response = await fetch(apiUrl(`/api/items/${id}`), {
+      method: 'DELETE',
+    })
+    if (!response.ok) {
+      throw httpError('deleting item', response)
+    }
+  }
+
+  const requestRestore = async (id: number) => {
+    const response = await fetch(apiUrl(`/api/items/${id}/restore`), {
+      method: 'POST',
+    })
+    if (!response.ok) {
+      throw httpError('restoring item', response)
+    }
+  }
+
+  // Rename an item, showing the new name straight away. Throws on failure
+  // (after restoring the old name) so the list can report it.
+  const editItem = async (item: Item, name: string) => {
+    try {
+      const renamed = (current: Item[]) =>
+        current.map(existing => existing.id === item.id ? { ...existing, name } : existing)
+      const updated = await optimistic(setItems, renamed, async () => {
+        const response = await fetch(apiUrl(`/api/items/${item.id}`), {
+          method: 'PATCH',
+          headers: {
+            'Content-Type': 'application/json',
+          },
+          // The version makes the server reject the edit if someone else changed the item
+          body: JSON.stringify({ name, version: item.version }),
+        })
+
+        if (!response.ok) {
+          throw httpError('editing item', response)
+        }
+
+        return await response.json() as Item
+      })
+      setItems(current => current.map(existing => existing.id === updated.id ? updated : existing))
+      undoStack.push({
+        undo: async () => { await requestRename(item.id, item.name); fetchItemsRef.current() },
+        redo: async () => { await requestRename(item.id, name); fetchItemsRef.current() },
+      })
+    } catch (err) {
+      console.error('Error editing item:', err)
+      throw err
+    }
+  }
+
+  // Trash every selected item in one request. Items someone else deleted
+  // meanwhile are reported by the server as missing rather than failing the rest.
+  const deleteSelected = async () => {
+    const ids = [...selected]
+    try {
+      const without = (current: Item[]) => current.filter(item => !selected.has(item.id))
+      const result = await optimistic(setItems, without, async () => {
+        const response = await fetch(apiUrl('/api/items/delete-ids'), {
+          method: 'POST',
+          headers: {
+            'Content-Type': 'application/json',
+          },
+          body: JSON.stringify({ ids }),
+        })
+
+        if (!response.ok) {
+          throw httpError('deleting items', response)
+        }
+
+        return await response.json() as { deleted: number[]; missing: number[] }
+      })
+      setSelected(new Set())
+      setNotice(result.missing.length > 0 ? t('items.alreadyDeleted') : null)
+      undoStack.push({
+        undo: async () => { await Promise.all(result.deleted.map(requestRestore)); fetchItemsRef.current() },
+        redo: async () => { await Promise.all(result.deleted.map(requestDelete)); fetchItemsRef.current() },
+      })
+
+      // Refresh the items list so the page fills up again
+      fetchItems()
+    } catch (err) {
+      console.error('Error deleting items:', err)
+      setError(err instanceof Error ? err.message : 'Unknown error')
     }
   }
 
-  // Delete an item
+  // Tick an item off or reopen it. While completed items are hidden, a ticked
+  // item leaves the list straight away.
+  const toggleCompleted = async (item: Item, completed: boolean) => {
+    try {
+      const toggled = (current: Item[]) => hideCompleted && completed
+        ? current.filter(existing => existing.id !== item.id)
+        : current.map(existing => existing.id === item.id ? { ...existing, completed } : existing)
+      await optimistic(setItems, toggled, async () => {
+        const response = await fetch(apiUrl(`/api/items/${item.id}`), {
+          method: 'PATCH',
+          headers: {
+            'Content-Type': 'application/json',
+          },
+          body: JSON.stringify({ completed, version: item.version }),
+        })
+
+        if (!response.ok) {
+          throw httpError('updating item', response)
+        }
+      })
+
+      // Refresh to pick up the new version and refill a filtered page
+      fetchItems()
+    } catch (err) {
+      console.error('Error updating item:', err)
+      setError(err instanceof Error ? err.message : 'Unknown error')
+    }
+  }
+
+  const changeHideCompleted = (hide: boolean) => {
+    undoStack.clear()
+    saveHideCompleted(hide)
+    setHideCompleted(hide)
+    setPage(1)
+  }
+
+  // Paging by hand starts a fresh undo history. Stepping back because deletions
+  // emptied the last page doesn't, so that delete can still be undone.
+  const changePage = (next: number) => {
+    undoStack.clear()
+    setPage(next)
+  }
+
+  // Delete an item, removing it from the list before the server responds
   const deleteItem = async (id: number) => {
     try {
-      const response = await fetch(`/api/items/${id}`, {
-        method: 'DELETE',
+      const without = (current: Item[]) => current.filter(item => item.id !== id)
+      await optimistic(setItems, without, () => requestDelete(id))
+      undoStack.push({
+        undo: async () => { await requestRestore(id); fetchItemsRef.current() },
+        redo: async () => { await requestDelete(id); fetchItemsRef.current() },
       })
-      
-      if (!response.ok) {
-        throw new Error(`Error deleting item: ${response.status}`)
-      }
-      
-      // Refresh the items list
+
+      // Refresh the items list so the page fills up again
       fetchItems()
     } catch (err) {
       console.error('Error deleting item:', err)
@@ -78,31 +348,112 @@ function App() {
     }
   }
 
-  // Fetch items on component mount
-  useEffect(() => {
+  // Ask the backend whether its database is reachable, so an outage shows as
+  // a banner instead of a generic error
+  const checkHealth = async () => {
+    try {
+      const response = await fetch(apiUrl('/api/health'))
+      setUnavailable(response.status === 503)
+    } catch (err) {
+      console.error('Error checking backend health:', err)
+    }
+  }
+
+  const retry = async () => {
+    undoStack.clear()
+    busyRetries.current = 0
+    await checkHealth()
     fetchItems()
+  }
+
+  useEffect(() => {
+    checkHealth()
   }, [])
 
+  // Ctrl+Z undoes the last delete or rename, Ctrl+Shift+Z or Ctrl+Y redoes it.
+  // Inside text fields the keys are left to the browser's own text undo.
+  useEffect(() => {
+    const onKeyDown = (event: KeyboardEvent) => {
+      if (!(event.ctrlKey || event.metaKey)) {
+        return
+      }
+      const target = event.target as HTMLElement | null
+      if (target?.closest('input, textarea, [contenteditable="true"]')) {
+        return
+      }
+      const key = event.key.toLowerCase()
+      const redo = key === 'y' || (key === 'z' && event.shiftKey)
+      if (key !== 'z' && !redo) {
+        return
+      }
+      event.preventDefault()
+      const step = redo ? undoStack.redo() : undoStack.undo()
+      step.catch(err => {
+        console.error('Error undoing change:', err)
+        setError(err instanceof Error ? err.message : 'Unknown error')
+      })
+    }
+    window.addEventListener('keydown', onKeyDown)
+    return () => window.removeEventListener('keydown', onKeyDown)
+  }, [undoStack])
+
+  // Fetch items on mount and whenever the page or filter changes. Undo entries
+  // refetch whichever page is shown when they run.
+  useEffect(() => {
+    setSelected(new Set())
+    setNotice(null)
+    busyRetries.current = 0
+    fetchItems()
+    // A retry scheduled for the previous page or filter, or after unmount, is dropped
+    return () => window.clearTimeout(retryTimer.current)
+  }, [page, hideCompleted])
+
   return (
     <div className="container">
-      <h1>AI-Friendly Repository</h1>
+      <h1>{t('app.title')}</h1>
       
       <div className="card">
-        <h2>Add New Item</h2>
+        <h2>{t('items.addHeading')}</h2>
         <ItemForm onAddItem={addItem} />
       </div>
       
       <div className="card">
-        <h2>Items</h2>
-        {loading ? (
-          <p>Loading items...</p>
+        <h2>{t('items.listHeading')}</h2>
+        <label className="list-option">
+          <input
+            type="checkbox"
+            checked={hideCompleted}
+            onChange={e => changeHideCompleted(e.target.checked)}
+          />
+          {t('items.hideCompleted')}
+        </label>
+        {offline && <p className="banner" role="status">{t('app.offline')}</p>}
+        {notice && <p className="banner" role="status">{notice}</p>}
+        {unavailable ? (
+          <div className="banner" role="alert">
+            <p>{t('app.unavailable')}</p>
+            <button onClick={retry}>{t('app.retry')}</button>
+          </div>
+        ) : busy ? (
+          <p className="banner" role="status">{t('app.busy')}</p>
+        ) : loading ? (
+          <SkeletonList />
         ) : error ? (
-          <p className="error">Error: {error}</p>
+          <p className="error">{t('items.error')} {error}</p>
         ) : items.length === 0 ? (
-          <p>No items found. Add some!</p>
+          <p>{t('items.empty')}</p>
         ) : (
-          <ItemList items={items} onDeleteItem={deleteItem} />
+          <ItemList
+            items={items}
+            onDeleteItem={deleteItem}
+            onEditItem={editItem}
+            onToggleCompleted={toggleCompleted}
+            selected={selected}
+            onSelectionChange={setSelected}
+            onDeleteSelected={deleteSelected}
+          />
         )}
+        <Pagination page={page} totalPages={totalPages} onChange={changePage} />
       </div>
     </div>
   )
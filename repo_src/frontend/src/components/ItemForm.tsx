@@ -1,29 +1,65 @@
-import { useState, FormEvent } from 'react'
+import { useState, useEffect, useRef, FormEvent } from 'react'
+import { NewItem } from '../types/item'
+import { useT } from '../i18n/LangContext'
+import { clearDraft, loadDraft, saveDraft } from '../utils/draft'
+import { itemNameProblem } from '../utils/itemName'
+
+// Wait for a pause in typing before writing the draft to storage
+const DRAFT_SAVE_DELAY_MS = 300
 
 interface ItemFormProps {
-  onAddItem: (name: string, description: string) => Promise<void>
+  onAddItem: (newItem: NewItem, idempotencyKey: string) => Promise<void>
 }
 
 function ItemForm({ onAddItem }: ItemFormProps) {
-  const [name, setName] = useState('')
-  const [description, setDescription] = useState('')
+  const t = useT()
+  const [name, setName] = useState(() => loadDraft().name)
+  const [description, setDescription] = useState(() => loadDraft().description)
+  const [markdown, setMarkdown] = useState(false)
   const [isSubmitting, setIsSubmitting] = useState(false)
+  // One key per submission: a retry of the same input sends the same key, so the
+  // server returns the item it already created instead of adding a duplicate
+  const [idempotencyKey, setIdempotencyKey] = useState(() => loadDraft().idempotencyKey ?? crypto.randomUUID())
+  // Set once the key has been sent; editing the input afterwards makes it a new submission
+  const keySent = useRef(loadDraft().idempotencyKey !== undefined)
+
+  useEffect(() => {
+    const draft = { name, description, idempotencyKey: keySent.current ? idempotencyKey : undefined }
+    const timer = setTimeout(() => saveDraft(draft), DRAFT_SAVE_DELAY_MS)
+    return () => clearTimeout(timer)
+  }, [name, description, idempotencyKey])
+
+  // Wrap a field setter so that changing the input after a failed submit uses a fresh key
+  const edited = <T,>(set: (value: T) => void) => (value: T) => {
+    if (keySent.current) {
+      keySent.current = false
+      setIdempotencyKey(crypto.randomUUID())
+    }
+    set(value)
+  }
 
   const handleSubmit = async (e: FormEvent) => {
     e.preventDefault()
     
-    if (!name.trim()) {
-      alert('Name is required')
+    const problem = itemNameProblem(name)
+    if (problem) {
+      alert(t(problem === 'required' ? 'form.nameRequired' : 'form.nameTooLong'))
       return
     }
     
     try {
       setIsSubmitting(true)
-      await onAddItem(name, description)
+      keySent.current = true
+      saveDraft({ name, description, idempotencyKey })
+      await onAddItem({ name, description: description || null, markdown }, idempotencyKey)
       
-      // Reset form after successful submission
+      // Reset form and discard the saved draft after successful submission
+      keySent.current = false
+      setIdempotencyKey(crypto.randomUUID())
       setName('')
       setDescription('')
+      setMarkdown(false)
+      clearDraft()
     } catch (error) {
       console.error('Error in form submission:', error)
     } finally {
@@ -34,33 +70,45 @@ function ItemForm({ onAddItem }: ItemFormProps) {
   return (
     <form onSubmit={handleSubmit}>
       <div className="form-group">
-        <label htmlFor="name">Name:</label>
+        <label htmlFor="name">{t('form.name')}</label>
         <input
           type="text"
           id="name"
           value={name}
-          onChange={(e) => setName(e.target.value)}
+          onChange={(e) => edited(setName)(e.target.value)}
           disabled={isSubmitting}
           required
         />
       </div>
       
       <div className="form-group">
-        <label htmlFor="description">Description:</label>
+        <label htmlFor="description">{t('form.description')}</label>
         <textarea
           id="description"
           value={description}
-          onChange={(e) => setDescription(e.target.value)}
+          onChange={(e) => edited(setDescription)(e.target.value)}
           disabled={isSubmitting}
         />
       </div>
+
+      <div className="form-group">
+        <label>
+          <input
+            type="checkbox"
+            checked={markdown}
+            onChange={(e) => edited(setMarkdown)(e.target.checked)}
+            disabled={isSubmitting}
+          />
+          {' '}{t('form.markdown')}
+        </label>
+      </div>
       
       <button 
         type="submit" 
         className="button-primary"
         disabled={isSubmitting}
       >
-        {isSubmitting ? 'Adding...' : 'Add Item'}
+        {isSubmitting ? t('form.submitting') : t('form.submit')}
       </button>
     </form>
   )
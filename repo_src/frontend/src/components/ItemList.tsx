@@ -1,53 +1,280 @@
-import React from 'react'
-
-interface Item {
-  id: number
-  name: string
-  description: string | null
-  created_at: string
-  updated_at: string
-}
+import React, { KeyboardEvent, useEffect, useRef, useState } from 'react'
+import { Item } from '../types/item'
+import { useT } from '../i18n/LangContext'
+import { copyText, itemLink } from '../utils/clipboard'
+
+// How long the copy confirmation stays visible
+const COPY_STATUS_MS = 2000
 
 interface ItemListProps {
   items: Item[]
   onDeleteItem: (id: number) => Promise<void>
+  onEditItem: (item: Item, name: string) => Promise<void>
+  onToggleCompleted: (item: Item, completed: boolean) => Promise<void>
+  // Ids ticked for a bulk action
+  selected: Set<number>
+  onSelectionChange: (selected: Set<number>) => void
+  onDeleteSelected: () => Promise<void>
 }
 
-function ItemList({ items, onDeleteItem }: ItemListProps) {
+function ItemList({
+  items,
+  onDeleteItem,
+  onEditItem,
+  onToggleCompleted,
+  selected,
+  onSelectionChange,
+  onDeleteSelected,
+}: ItemListProps) {
+  const t = useT()
+  const rowRefs = useRef<(HTMLLIElement | null)[]>([])
+  const previousCount = useRef(items.length)
+  const [announcement, setAnnouncement] = useState('')
+  // Inline editing: at most one row is edited at a time. While a save is in
+  // flight the new name is shown with a pending style; on failure the row goes
+  // back to the item's current name and shows an error.
+  const [editingId, setEditingId] = useState<number | null>(null)
+  const [draft, setDraft] = useState('')
+  const [pending, setPending] = useState<{ id: number; name: string } | null>(null)
+  const [failedId, setFailedId] = useState<number | null>(null)
+  // Set when Escape cancels an edit, so the blur that follows doesn't save it
+  const cancelEdit = useRef(false)
+  // Result of the last copy action, shown briefly on its row
+  const [copyStatus, setCopyStatus] = useState<{ id: number; ok: boolean } | null>(null)
+  const copyStatusTimer = useRef<ReturnType<typeof setTimeout>>()
+
+  useEffect(() => () => clearTimeout(copyStatusTimer.current), [])
+
+  // Announce additions/removals to screen readers via the aria-live region
+  useEffect(() => {
+    if (items.length > previousCount.current) {
+      setAnnouncement(t('items.announceAdded'))
+    } else if (items.length < previousCount.current) {
+      setAnnouncement(t('items.announceRemoved'))
+    }
+    previousCount.current = items.length
+  }, [items.length, t])
+
   const formatDate = (dateString: string) => {
     const date = new Date(dateString)
     return date.toLocaleDateString()
   }
 
   const handleDelete = async (id: number) => {
-    if (window.confirm('Are you sure you want to delete this item?')) {
+    if (window.confirm(t('items.deleteConfirm'))) {
       await onDeleteItem(id)
     }
   }
 
+  const allSelected = items.length > 0 && items.every(item => selected.has(item.id))
+
+  // Select or clear every row shown
+  const toggleAll = () => {
+    onSelectionChange(allSelected ? new Set() : new Set(items.map(item => item.id)))
+  }
+
+  const toggleSelected = (id: number) => {
+    const next = new Set(selected)
+    if (!next.delete(id)) {
+      next.add(id)
+    }
+    onSelectionChange(next)
+  }
+
+  const handleDeleteSelected = async () => {
+    if (window.confirm(t('items.deleteSelectedConfirm'))) {
+      await onDeleteSelected()
+    }
+  }
+
+  const focusRow = (index: number) => {
+    rowRefs.current[Math.max(0, Math.min(index, items.length - 1))]?.focus()
+  }
+
+  const handleCopy = async (id: number, text: string) => {
+    const ok = await copyText(text)
+    setCopyStatus({ id, ok })
+    clearTimeout(copyStatusTimer.current)
+    copyStatusTimer.current = setTimeout(() => setCopyStatus(null), COPY_STATUS_MS)
+  }
+
+  const startEdit = (item: Item) => {
+    if (pending?.id === item.id) {
+      return
+    }
+    cancelEdit.current = false
+    setDraft(item.name)
+    setFailedId(null)
+    setEditingId(item.id)
+  }
+
+  // Runs when the input loses focus: Enter and Escape both move focus back to
+  // the row, and clicking elsewhere blurs it too
+  const finishEdit = async (item: Item) => {
+    setEditingId(null)
+    const name = draft.trim()
+    if (cancelEdit.current || !name || name === item.name) {
+      return
+    }
+    setPending({ id: item.id, name })
+    try {
+      await onEditItem(item, name)
+    } catch {
+      setFailedId(item.id)
+    } finally {
+      setPending(null)
+    }
+  }
+
+  const handleEditKeyDown = (e: KeyboardEvent<HTMLInputElement>, index: number) => {
+    if (e.key === 'Enter' || e.key === 'Escape') {
+      e.preventDefault()
+      cancelEdit.current = e.key === 'Escape'
+      focusRow(index)
+    }
+  }
+
+  // Arrow keys move between rows; Enter/F2 edits and Delete/Backspace deletes the
+  // focused row. Only handled when the row itself has focus, not its buttons or input.
+  const handleKeyDown = (e: KeyboardEvent<HTMLLIElement>, index: number, item: Item) => {
+    if (e.target !== e.currentTarget) {
+      return
+    }
+    switch (e.key) {
+      case 'ArrowDown':
+        e.preventDefault()
+        focusRow(index + 1)
+        break
+      case 'ArrowUp':
+        e.preventDefault()
+        focusRow(index - 1)
+        break
+      case 'Home':
+        e.preventDefault()
+        focusRow(0)
+        break
+      case 'End':
+        e.preventDefault()
+        focusRow(items.length - 1)
+        break
+      case 'Enter':
+      case 'F2':
+        e.preventDefault()
+        startEdit(item)
+        break
+      case 'Delete':
+      case 'Backspace':
+        e.preventDefault()
+        handleDelete(item.id)
+        break
+    }
+  }
+
   return (
-    <ul className="item-list">
-      {items.map(item => (
-        <li key={item.id} className="item">
-          <div className="item-content">
-            <div className="item-name">{item.name}</div>
-            {item.description && (
-              <div className="item-description">{item.description}</div>
-            )}
-            <div className="item-date">
-              Created: {formatDate(item.created_at)}
-            </div>
-          </div>
-          <button 
-            onClick={() => handleDelete(item.id)}
-            className="item-delete"
+    <>
+      <div className="bulk-toolbar">
+        <label>
+          <input type="checkbox" checked={allSelected} onChange={toggleAll} />
+          {t('items.selectAll')}
+        </label>
+        {selected.size > 0 && (
+          <>
+            <span>{t('items.selectedCount')} {selected.size}</span>
+            <button onClick={handleDeleteSelected} className="item-delete">
+              {t('items.deleteSelected')}
+            </button>
+          </>
+        )}
+      </div>
+      <ul className="item-list" role="list" aria-label={t('items.listHeading')}>
+        {items.map((item, index) => (
+          <li
+            key={item.id}
+            className={item.completed ? 'item item-completed' : 'item'}
+            id={`item-${item.id}`}
+            role="listitem"
+            tabIndex={0}
+            ref={el => { rowRefs.current[index] = el }}
+            onKeyDown={e => handleKeyDown(e, index, item)}
+            aria-busy={pending?.id === item.id}
           >
-            Delete
-          </button>
-        </li>
-      ))}
-    </ul>
+            <input
+              type="checkbox"
+              className="item-select"
+              aria-label={t('items.select')}
+              checked={selected.has(item.id)}
+              onChange={() => toggleSelected(item.id)}
+            />
+            <input
+              type="checkbox"
+              className="item-completed-toggle"
+              aria-label={t('items.completed')}
+              checked={item.completed}
+              onChange={e => onToggleCompleted(item, e.target.checked)}
+            />
+            <div className="item-content">
+              {editingId === item.id ? (
+                <input
+                  className="item-name-input"
+                  aria-label={t('items.editLabel')}
+                  value={draft}
+                  onChange={e => setDraft(e.target.value)}
+                  onKeyDown={e => handleEditKeyDown(e, index)}
+                  onBlur={() => finishEdit(item)}
+                  autoFocus
+                />
+              ) : pending?.id === item.id ? (
+                <div className="item-name item-pending">
+                  {pending.name} <span className="item-status">{t('items.saving')}</span>
+                </div>
+              ) : (
+                <div className="item-name" onDoubleClick={() => startEdit(item)}>{item.name}</div>
+              )}
+              {failedId === item.id && (
+                <div className="error" role="alert">{t('items.editFailed')}</div>
+              )}
+              {item.description_html ? (
+                // Rendered from Markdown and sanitized by the backend
+                <div className="item-description" dangerouslySetInnerHTML={{ __html: item.description_html }} />
+              ) : item.description && (
+                <div className="item-description">{item.description}</div>
+              )}
+              <div className="item-date">
+                {t('items.created')} {formatDate(item.created_at)}
+              </div>
+              {copyStatus?.id === item.id && (
+                <div className={copyStatus.ok ? 'item-status' : 'error'} role="status">
+                  {copyStatus.ok ? t('items.copied') : t('items.copyFailed')}
+                </div>
+              )}
+            </div>
+            <button onClick={() => handleCopy(item.id, item.name)} className="item-copy">
+              {t('items.copy')}
+            </button>
+            <button onClick={() => handleCopy(item.id, itemLink(item.id))} className="item-copy">
+              {t('items.copyLink')}
+            </button>
+            <button
+              onClick={() => startEdit(item)}
+              className="item-edit"
+              disabled={pending?.id === item.id}
+            >
+              {t('items.edit')}
+            </button>
+            <button 
+              onClick={() => handleDelete(item.id)}
+              className="item-delete"
+            >
+              {t('items.delete')}
+            </button>
+          </li>
+        ))}
+      </ul>
+      <div className="visually-hidden" aria-live="polite" role="status">
+        {announcement}
+      </div>
+    </>
   )
 }
 
-export default ItemList 
\ No newline at end of file
+export default ItemList
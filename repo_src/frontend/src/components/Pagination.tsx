@@ -0,0 +1,51 @@
+import { useT } from '../i18n/LangContext'
+
+interface PaginationProps {
+  page: number
+  totalPages: number
+  onChange: (page: number) => void
+  // How many numbered page buttons to show at most
+  maxButtons?: number
+}
+
+// Page numbers to show as buttons: a window of up to `maxButtons` pages that
+// keeps the current page as close to the middle as the boundaries allow
+export function visiblePages(page: number, totalPages: number, maxButtons: number): number[] {
+  const count = Math.min(maxButtons, totalPages)
+  const first = Math.max(1, Math.min(page - Math.floor(count / 2), totalPages - count + 1))
+  return Array.from({ length: count }, (_, index) => first + index)
+}
+
+// Previous/next and numbered page controls for any paginated listing
+function Pagination({ page, totalPages, onChange, maxButtons = 5 }: PaginationProps) {
+  const t = useT()
+
+  if (totalPages <= 1) {
+    return null
+  }
+
+  return (
+    <nav className="pagination" aria-label={t('pagination.label')}>
+      <button onClick={() => onChange(page - 1)} disabled={page <= 1}>
+        {t('pagination.previous')}
+      </button>
+      {visiblePages(page, totalPages, maxButtons).map(number => (
+        <button
+          key={number}
+          onClick={() => onChange(number)}
+          className={number === page ? 'pagination-current' : undefined}
+          aria-current={number === page ? 'page' : undefined}
+          aria-label={`${t('pagination.page')} ${number}`}
+          disabled={number === page}
+        >
+          {number}
+        </button>
+      ))}
+      <button onClick={() => onChange(page + 1)} disabled={page >= totalPages}>
+        {t('pagination.next')}
+      </button>
+    </nav>
+  )
+}
+
+export default Pagination
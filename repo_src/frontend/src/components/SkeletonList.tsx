@@ -0,0 +1,30 @@
+import { useT } from '../i18n/LangContext'
+
+interface SkeletonListProps {
+  rows?: number
+}
+
+// Placeholder rows shaped like ItemList entries, shown while items load
+function SkeletonList({ rows = 3 }: SkeletonListProps) {
+  const t = useT()
+
+  return (
+    <div aria-busy="true">
+      <span className="visually-hidden" role="status">{t('items.loading')}</span>
+      <ul className="item-list" aria-hidden="true">
+        {Array.from({ length: rows }, (_, index) => (
+          <li key={index} className="item skeleton-item">
+            <div className="item-content">
+              <div className="skeleton-line skeleton-name" />
+              <div className="skeleton-line skeleton-description" />
+              <div className="skeleton-line skeleton-date" />
+            </div>
+            <div className="skeleton-line skeleton-button" />
+          </li>
+        ))}
+      </ul>
+    </div>
+  )
+}
+
+export default SkeletonList
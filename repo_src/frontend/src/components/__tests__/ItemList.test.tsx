@@ -0,0 +1,40 @@
+import { describe, expect, it } from 'vitest'
+import { renderToStaticMarkup } from 'react-dom/server'
+import ItemList from '../ItemList'
+import { Item } from '../../types/item'
+
+const item: Item = {
+  id: 1,
+  name: '<script>alert("x")</script>',
+  description: '<img src=x onerror=alert(1)>',
+  kind: 'item',
+  markdown: false,
+  description_html: null,
+  completed: false,
+  user_id: null,
+  version: 1,
+  created_at: '2024-01-01T00:00:00',
+  updated_at: '2024-01-01T00:00:00',
+  bumped_at: null,
+  deleted_at: null,
+}
+
+describe('ItemList', () => {
+  it('renders item text as inert text, not HTML', () => {
+    const html = renderToStaticMarkup(
+      <ItemList
+        items={[item]}
+        onDeleteItem={async () => {}}
+        onEditItem={async () => {}}
+        onToggleCompleted={async () => {}}
+        selected={new Set()}
+        onSelectionChange={() => {}}
+        onDeleteSelected={async () => {}}
+      />
+    )
+    expect(html).toContain('&lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt;')
+    expect(html).toContain('&lt;img src=x onerror=alert(1)&gt;')
+    expect(html).not.toContain('<script>')
+    expect(html).not.toContain('<img')
+  })
+})
@@ -0,0 +1,25 @@
+import { createContext, ReactNode, useCallback, useContext, useState } from 'react'
+import { detectLang, Lang, StringKey, t } from './strings'
+
+interface LangContextValue {
+  lang: Lang
+  setLang: (lang: Lang) => void
+}
+
+const LangContext = createContext<LangContextValue>({ lang: 'en', setLang: () => {} })
+
+// Provides the active language, defaulting to the browser's preferred languages
+export function LangProvider({ children }: { children: ReactNode }) {
+  const [lang, setLang] = useState<Lang>(() => detectLang(navigator.languages ?? [navigator.language]))
+  return <LangContext.Provider value={{ lang, setLang }}>{children}</LangContext.Provider>
+}
+
+export function useLang() {
+  return useContext(LangContext)
+}
+
+// Returns a translate function bound to the active language
+export function useT() {
+  const { lang } = useLang()
+  return useCallback((key: StringKey) => t(key, lang), [lang])
+}
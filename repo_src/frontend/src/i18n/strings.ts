@@ -0,0 +1,115 @@
+// Static string tables for the UI. English is the reference table: every other
+// language must provide the same keys (enforced by the Record type below).
+const en = {
+  'app.title': 'AI-Friendly Repository',
+  'app.unavailable': 'The service is temporarily unavailable. Your items are safe; please try again in a moment.',
+  'app.retry': 'Try again',
+  'app.busy': 'The server is busy right now. Retrying shortly…',
+  'app.offline': 'You appear to be offline. Showing the items saved from your last visit.',
+  'items.addHeading': 'Add New Item',
+  'items.listHeading': 'Items',
+  'items.hideCompleted': 'Hide completed',
+  'items.completed': 'Done',
+  'items.select': 'Select',
+  'items.selectAll': 'Select all',
+  'items.selectedCount': 'Selected:',
+  'items.deleteSelected': 'Delete selected',
+  'items.deleteSelectedConfirm': 'Are you sure you want to delete the selected items?',
+  'items.alreadyDeleted': 'Some of the selected items had already been deleted.',
+  'items.loading': 'Loading items...',
+  'items.error': 'Error:',
+  'items.empty': 'No items found. Add some!',
+  'items.created': 'Created:',
+  'items.delete': 'Delete',
+  'items.deleteConfirm': 'Are you sure you want to delete this item?',
+  'items.edit': 'Edit',
+  'items.editLabel': 'Item name',
+  'items.saving': 'Saving...',
+  'items.editFailed': 'Could not save the change',
+  'items.copy': 'Copy',
+  'items.copyLink': 'Copy link',
+  'items.copied': 'Copied!',
+  'items.copyFailed': 'Could not copy; the browser blocked clipboard access',
+  'items.announceAdded': 'Item added',
+  'items.announceRemoved': 'Item removed',
+  'form.name': 'Name:',
+  'form.description': 'Description:',
+  'form.markdown': 'Format the description with Markdown',
+  'form.nameRequired': 'Name is required',
+  'form.nameTooLong': 'Name must be at most 100 characters',
+  'form.submit': 'Add Item',
+  'form.submitting': 'Adding...',
+  'pagination.label': 'Pages',
+  'pagination.previous': 'Previous',
+  'pagination.next': 'Next',
+  'pagination.page': 'Page',
+}
+
+export type StringKey = keyof typeof en
+
+export type Lang = 'en' | 'es'
+
+export const SUPPORTED_LANGS: Lang[] = ['en', 'es']
+
+const es: Record<StringKey, string> = {
+  'app.title': 'Repositorio AI-Friendly',
+  'app.unavailable': 'El servicio no está disponible temporalmente. Tus elementos están a salvo; inténtalo de nuevo en un momento.',
+  'app.retry': 'Reintentar',
+  'app.busy': 'El servidor está ocupado en este momento. Reintentando en breve…',
+  'app.offline': 'Parece que no tienes conexión. Se muestran los elementos guardados de tu última visita.',
+  'items.addHeading': 'Añadir elemento',
+  'items.listHeading': 'Elementos',
+  'items.hideCompleted': 'Ocultar completados',
+  'items.completed': 'Hecho',
+  'items.select': 'Seleccionar',
+  'items.selectAll': 'Seleccionar todo',
+  'items.selectedCount': 'Seleccionados:',
+  'items.deleteSelected': 'Eliminar seleccionados',
+  'items.deleteSelectedConfirm': '¿Seguro que quieres eliminar los elementos seleccionados?',
+  'items.alreadyDeleted': 'Algunos de los elementos seleccionados ya se habían eliminado.',
+  'items.loading': 'Cargando elementos...',
+  'items.error': 'Error:',
+  'items.empty': 'No hay elementos. ¡Añade alguno!',
+  'items.created': 'Creado:',
+  'items.delete': 'Eliminar',
+  'items.deleteConfirm': '¿Seguro que quieres eliminar este elemento?',
+  'items.edit': 'Editar',
+  'items.editLabel': 'Nombre del elemento',
+  'items.saving': 'Guardando...',
+  'items.editFailed': 'No se pudo guardar el cambio',
+  'items.copy': 'Copiar',
+  'items.copyLink': 'Copiar enlace',
+  'items.copied': '¡Copiado!',
+  'items.copyFailed': 'No se pudo copiar; el navegador bloqueó el portapapeles',
+  'items.announceAdded': 'Elemento añadido',
+  'items.announceRemoved': 'Elemento eliminado',
+  'form.name': 'Nombre:',
+  'form.description': 'Descripción:',
+  'form.markdown': 'Dar formato a la descripción con Markdown',
+  'form.nameRequired': 'El nombre es obligatorio',
+  'form.nameTooLong': 'El nombre debe tener como máximo 100 caracteres',
+  'form.submit': 'Añadir',
+  'form.submitting': 'Añadiendo...',
+  'pagination.label': 'Páginas',
+  'pagination.previous': 'Anterior',
+  'pagination.next': 'Siguiente',
+  'pagination.page': 'Página',
+}
+
+const tables: Record<Lang, Record<StringKey, string>> = { en, es }
+
+// Look up a UI string, falling back to English if a translation is missing
+export function t(key: StringKey, lang: Lang): string {
+  return tables[lang][key] ?? en[key]
+}
+
+// Pick the first supported language from a list of BCP 47 tags (e.g. navigator.languages)
+export function detectLang(preferred: readonly string[]): Lang {
+  for (const tag of preferred) {
+    const base = tag.toLowerCase().split('-')[0]
+    if ((SUPPORTED_LANGS as string[]).includes(base)) {
+      return base as Lang
+    }
+  }
+  return 'en'
+}
@@ -0,0 +1,40 @@
+// Kinds of item the backend accepts (mirrors ItemKind in data/schemas.py)
+export type ItemKind = 'item' | 'note' | 'task'
+
+// Item as returned by the backend (mirrors ItemResponse in data/schemas.py)
+export interface Item {
+  id: number
+  name: string
+  description: string | null
+  kind: ItemKind
+  // Whether the description is Markdown
+  markdown: boolean
+  // Sanitized HTML of a Markdown description, when the backend renders Markdown
+  description_html: string | null
+  completed: boolean
+  user_id: string | null
+  version: number
+  created_at: string
+  updated_at: string
+  // Set when the item was last bumped to the top of newest-first lists
+  bumped_at: string | null
+  deleted_at: string | null
+}
+
+// Fields a client sends to create an item (mirrors ItemCreate);
+// id, owner and timestamps are assigned by the server
+export interface NewItem {
+  name: string
+  description: string | null
+  kind?: ItemKind
+  markdown?: boolean
+}
+
+// One page of a paginated listing (mirrors Page in data/schemas.py)
+export interface Page<T> {
+  items: T[]
+  total: number
+  page: number
+  per_page: number
+  has_next: boolean
+}
@@ -0,0 +1,19 @@
+import { describe, expect, it } from 'vitest'
+import { itemNameProblem } from '../itemName'
+import cases from '../../../../backend/data/item_name_cases.json'
+
+// The same table backend/tests/test_validators.py runs through ItemCreate
+interface NameCase {
+  case: string
+  name?: string
+  repeat?: string
+  count?: number
+  valid: boolean
+}
+
+describe('itemNameProblem', () => {
+  it.each(cases as NameCase[])('agrees with the backend: $case', ({ name, repeat, count, valid }) => {
+    const text = name ?? (repeat ?? '').repeat(count ?? 0)
+    expect(itemNameProblem(text) === null).toBe(valid)
+  })
+})
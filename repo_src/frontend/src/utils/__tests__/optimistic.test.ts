@@ -0,0 +1,31 @@
+import { describe, expect, it } from 'vitest'
+import type { SetStateAction } from 'react'
+import { optimistic } from '../optimistic'
+
+// Minimal stand-in for a React state setter
+function fakeState<S>(initial: S) {
+  const state = { value: initial }
+  const setState = (action: SetStateAction<S>) => {
+    state.value = action instanceof Function ? action(state.value) : action
+  }
+  return { state, setState }
+}
+
+describe('optimistic', () => {
+  it('keeps the update when the request succeeds', async () => {
+    const { state, setState } = fakeState([1, 2])
+    const result = await optimistic(setState, list => [...list, 3], async () => 'ok')
+    expect(result).toBe('ok')
+    expect(state.value).toEqual([1, 2, 3])
+  })
+
+  it('rolls back and rethrows when the request fails', async () => {
+    const { state, setState } = fakeState([1, 2])
+    const failing = optimistic(setState, list => list.filter(n => n !== 1), async () => {
+      expect(state.value).toEqual([2])
+      throw new Error('offline')
+    })
+    await expect(failing).rejects.toThrow('offline')
+    expect(state.value).toEqual([1, 2])
+  })
+})
@@ -0,0 +1,55 @@
+import { describe, expect, it } from 'vitest'
+import { UndoStack } from '../undoStack'
+
+// An entry that logs its calls, to check the order actions are reversed in
+function entry(name: string, log: string[]) {
+  return {
+    undo: async () => { log.push(`undo ${name}`) },
+    redo: async () => { log.push(`redo ${name}`) },
+  }
+}
+
+describe('UndoStack', () => {
+  it('undoes in reverse order and redoes what was undone', async () => {
+    const log: string[] = []
+    const stack = new UndoStack()
+    stack.push(entry('a', log))
+    stack.push(entry('b', log))
+    expect(await stack.undo()).toBe(true)
+    expect(await stack.undo()).toBe(true)
+    expect(await stack.undo()).toBe(false)
+    expect(await stack.redo()).toBe(true)
+    expect(log).toEqual(['undo b', 'undo a', 'redo a'])
+  })
+
+  it('drops the oldest entries beyond the limit', async () => {
+    const log: string[] = []
+    const stack = new UndoStack(2)
+    stack.push(entry('a', log))
+    stack.push(entry('b', log))
+    stack.push(entry('c', log))
+    while (await stack.undo()) { /* drain */ }
+    expect(log).toEqual(['undo c', 'undo b'])
+  })
+
+  it('keeps a failed undo on the stack', async () => {
+    const stack = new UndoStack()
+    let fail = true
+    stack.push({
+      undo: async () => { if (fail) throw new Error('offline') },
+      redo: async () => {},
+    })
+    await expect(stack.undo()).rejects.toThrow('offline')
+    fail = false
+    expect(await stack.undo()).toBe(true)
+  })
+
+  it('forgets redo history when a new action is pushed', async () => {
+    const log: string[] = []
+    const stack = new UndoStack()
+    stack.push(entry('a', log))
+    await stack.undo()
+    stack.push(entry('b', log))
+    expect(await stack.redo()).toBe(false)
+  })
+})
@@ -0,0 +1,18 @@
+// Copy text with the async Clipboard API. Resolves to false instead of throwing
+// when the API is unavailable (e.g. not a secure context) or permission is denied.
+export async function copyText(text: string): Promise<boolean> {
+  if (!navigator.clipboard) {
+    return false
+  }
+  try {
+    await navigator.clipboard.writeText(text)
+    return true
+  } catch {
+    return false
+  }
+}
+
+// Link to an item's row in the list; the browser scrolls to it on load
+export function itemLink(id: number): string {
+  return `${window.location.origin}${import.meta.env.BASE_URL}#item-${id}`
+}
@@ -0,0 +1,44 @@
+// Persist the add-item form's unsent input in sessionStorage, so an accidental
+// reload doesn't lose it. Storage can be unavailable (e.g. blocked by privacy
+// settings), in which case drafts are simply not kept.
+
+const DRAFT_KEY = 'itemForm.draft'
+
+export interface ItemDraft {
+  name: string
+  description: string
+  // Idempotency-Key of a create that was sent but not confirmed, so resubmitting
+  // after a reload can't create the item twice
+  idempotencyKey?: string
+}
+
+const EMPTY_DRAFT: ItemDraft = { name: '', description: '' }
+
+export function loadDraft(): ItemDraft {
+  try {
+    const stored = JSON.parse(sessionStorage.getItem(DRAFT_KEY) ?? 'null')
+    if (stored && typeof stored.name === 'string' && typeof stored.description === 'string') {
+      const idempotencyKey = typeof stored.idempotencyKey === 'string' ? stored.idempotencyKey : undefined
+      return { name: stored.name, description: stored.description, idempotencyKey }
+    }
+  } catch {
+    // Unreadable or missing draft
+  }
+  return EMPTY_DRAFT
+}
+
+export function saveDraft(draft: ItemDraft): void {
+  try {
+    if (!draft.name && !draft.description) {
+      sessionStorage.removeItem(DRAFT_KEY)
+    } else {
+      sessionStorage.setItem(DRAFT_KEY, JSON.stringify(draft))
+    }
+  } catch {
+    // Storage unavailable or full; the draft just won't survive a reload
+  }
+}
+
+export function clearDraft(): void {
+  saveDraft(EMPTY_DRAFT)
+}
@@ -0,0 +1,28 @@
+// Prefix an absolute API path (e.g. '/api/items') with the app's base path,
+// so requests still reach the backend when the app is served under a sub-path
+export function apiUrl(path: string): string {
+  return `${import.meta.env.BASE_URL.replace(/\/$/, '')}${path}`
+}
+
+// Delay in milliseconds asked for by a response's Retry-After header (seconds or
+// an HTTP date), or null if it has none. The backend sends it when it is busy.
+export function retryAfterMs(response: Response): number | null {
+  const value = response.headers.get('Retry-After')
+  if (!value) {
+    return null
+  }
+  const seconds = Number(value)
+  if (Number.isFinite(seconds)) {
+    return Math.max(0, seconds * 1000)
+  }
+  const date = Date.parse(value)
+  return Number.isNaN(date) ? null : Math.max(0, date - Date.now())
+}
+
+// Build an error for a failed API response, including the server's request id
+// (X-Request-Id) so users can quote it when reporting a problem
+export function httpError(action: string, response: Response): Error {
+  const requestId = response.headers.get('X-Request-Id')
+  const suffix = requestId ? ` (request id ${requestId})` : ''
+  return new Error(`Error ${action}: ${response.status}${suffix}`)
+}
@@ -0,0 +1,25 @@
+// The item name rules of backend/data/validators.py, checked in the form before a
+// name is sent. Both copies are tested against backend/data/item_name_cases.json,
+// so a rule changed on one side only fails the tests.
+
+// Longest allowed name, in code points (mirrors ITEM_NAME_MAX_LENGTH)
+export const ITEM_NAME_MAX_LENGTH = 100
+
+// Control and bidirectional override characters the backend strips (mirrors UNSAFE_CHARACTERS)
+// eslint-disable-next-line no-control-regex
+const UNSAFE_CHARACTERS = /[\x00-\x08\x0b\x0c\x0e-\x1f\x7f\u202a-\u202e\u2066-\u2069]/g
+
+export type ItemNameProblem = 'required' | 'tooLong'
+
+// Why the backend would reject this name, or null if it would accept it
+export function itemNameProblem(name: string): ItemNameProblem | null {
+  const cleaned = name.replace(UNSAFE_CHARACTERS, '').normalize('NFC')
+  if (!cleaned.trim()) {
+    return 'required'
+  }
+  // Spreading splits by code point, so an emoji counts once as on the backend
+  if ([...cleaned].length > ITEM_NAME_MAX_LENGTH) {
+    return 'tooLong'
+  }
+  return null
+}
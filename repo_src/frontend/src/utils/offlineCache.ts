@@ -0,0 +1,57 @@
+// Keep the last fetched copy of each list page in IndexedDB, so the list can be
+// shown straight away on startup and read while offline. Storage may be missing
+// or blocked (private browsing, old browsers); every function then quietly does
+// nothing and the app works from the network alone.
+import { Item, Page } from '../types/item'
+
+const DB_NAME = 'items-offline'
+const DB_VERSION = 1
+const STORE = 'pages'
+
+function openDb(): Promise<IDBDatabase> {
+  return new Promise((resolve, reject) => {
+    const request = indexedDB.open(DB_NAME, DB_VERSION)
+    request.onupgradeneeded = () => {
+      request.result.createObjectStore(STORE)
+    }
+    request.onsuccess = () => resolve(request.result)
+    request.onerror = () => reject(request.error)
+  })
+}
+
+// Run one request against the store and close the database afterwards
+async function withStore<T>(mode: IDBTransactionMode, run: (store: IDBObjectStore) => IDBRequest<T>): Promise<T> {
+  const db = await openDb()
+  try {
+    return await new Promise<T>((resolve, reject) => {
+      const request = run(db.transaction(STORE, mode).objectStore(STORE))
+      request.onsuccess = () => resolve(request.result)
+      request.onerror = () => reject(request.error)
+    })
+  } finally {
+    db.close()
+  }
+}
+
+// `filter` is the list's filter query string, so filtered and unfiltered
+// copies of the same page are kept apart
+function pageKey(page: number, perPage: number, filter: string): string {
+  return filter ? `${page}:${perPage}:${filter}` : `${page}:${perPage}`
+}
+
+export async function loadCachedPage(page: number, perPage: number, filter = ''): Promise<Page<Item> | null> {
+  try {
+    const stored = await withStore<Page<Item> | undefined>('readonly', store => store.get(pageKey(page, perPage, filter)))
+    return stored ?? null
+  } catch {
+    return null
+  }
+}
+
+export async function saveCachedPage(page: number, perPage: number, data: Page<Item>, filter = ''): Promise<void> {
+  try {
+    await withStore('readwrite', store => store.put(data, pageKey(page, perPage, filter)))
+  } catch {
+    // Storage unavailable or full; the next visit just starts from the network
+  }
+}
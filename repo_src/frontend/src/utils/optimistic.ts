@@ -0,0 +1,27 @@
+// Optimistic updates: change local state straight away so the UI feels instant,
+// then talk to the server and undo the change if the request fails.
+import type { Dispatch, SetStateAction } from 'react'
+
+// Apply `update` to the state, then run `request`. If the request throws, the
+// state is reset to what it was just before the update and the error is rethrown,
+// so callers can still report it.
+export async function optimistic<S, R>(
+  setState: Dispatch<SetStateAction<S>>,
+  update: (current: S) => S,
+  request: () => Promise<R>,
+): Promise<R> {
+  // Wrapped so a state of `undefined` can be told apart from "not captured"
+  let snapshot = null as { value: S } | null
+  setState(current => {
+    snapshot = { value: current }
+    return update(current)
+  })
+  try {
+    return await request()
+  } catch (err) {
+    if (snapshot) {
+      setState(snapshot.value)
+    }
+    throw err
+  }
+}
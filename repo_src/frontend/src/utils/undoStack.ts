@@ -0,0 +1,64 @@
+// In-session history of reversible item actions (delete, rename) for Ctrl+Z /
+// Ctrl+Shift+Z. Each entry knows how to reverse and re-apply itself on the
+// server. The history only lives in memory and is capped at `limit` entries.
+
+export const UNDO_LIMIT = 20
+
+export interface UndoEntry {
+  undo: () => Promise<void>
+  redo: () => Promise<void>
+}
+
+export class UndoStack {
+  private done: UndoEntry[] = []
+  private undone: UndoEntry[] = []
+
+  constructor(private readonly limit: number = UNDO_LIMIT) {}
+
+  // Record a completed action; anything previously undone can no longer be redone
+  push(entry: UndoEntry): void {
+    this.done.push(entry)
+    if (this.done.length > this.limit) {
+      this.done.shift()
+    }
+    this.undone = []
+  }
+
+  // Reverse the most recent action. Resolves to false when there is nothing to
+  // undo; if the server call fails the entry stays on the stack and the error is thrown.
+  async undo(): Promise<boolean> {
+    const entry = this.done.pop()
+    if (!entry) {
+      return false
+    }
+    try {
+      await entry.undo()
+    } catch (err) {
+      this.done.push(entry)
+      throw err
+    }
+    this.undone.push(entry)
+    return true
+  }
+
+  // Re-apply the most recently undone action
+  async redo(): Promise<boolean> {
+    const entry = this.undone.pop()
+    if (!entry) {
+      return false
+    }
+    try {
+      await entry.redo()
+    } catch (err) {
+      this.undone.push(entry)
+      throw err
+    }
+    this.done.push(entry)
+    return true
+  }
+
+  clear(): void {
+    this.done = []
+    this.undone = []
+  }
+}
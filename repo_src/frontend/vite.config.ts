@@ -1,19 +1,26 @@
-import { defineConfig } from 'vite'
+import { defineConfig, loadEnv } from 'vite'
 import react from '@vitejs/plugin-react'
 
 // https://vitejs.dev/config/
-export default defineConfig({
-  plugins: [react()],
-  server: {
-    port: 5173, // Default Vite port
-    host: true, // Allow access from network
-    proxy: {
-      // Proxy API requests to backend
-      '/api': {
-        target: 'http://localhost:8000',
-        changeOrigin: true,
-        secure: false
+export default defineConfig(({ mode }) => {
+  const env = loadEnv(mode, process.cwd(), '')
+  // Sub-path the app is served under (e.g. /app/); must match SITE_BASE_PATH on the backend
+  const base = env.VITE_PUBLIC_PATH || '/'
+
+  return {
+    base,
+    plugins: [react()],
+    server: {
+      port: 5173, // Default Vite port
+      host: true, // Allow access from network
+      proxy: {
+        // Proxy API requests to backend
+        [`${base.replace(/\/$/, '')}/api`]: {
+          target: 'http://localhost:8000',
+          changeOrigin: true,
+          secure: false
+        }
       }
-    }
-  },
-}) 
\ No newline at end of file
+    },
+  }
+})